@@ -1,6 +1,6 @@
 #![no_main]
 
-use bahttext::words;
+use bahttext::{try_words, words};
 use libfuzzer_sys::fuzz_target;
 use arbitrary::{Arbitrary, Unstructured};
 
@@ -36,6 +36,7 @@ fuzz_target!(|data: &[u8]| {
     // Test with edge cases first
     for &value in &get_edge_cases() {
         let _ = words(value);
+        let _ = try_words(value);
     }
 
     // Skip if input is too small
@@ -62,7 +63,10 @@ fuzz_target!(|data: &[u8]| {
         let precise_value = (scaled_value * factor).round() / factor;
 
         // Test with the generated value
-        let _ = words(precise_value);
+        let text = words(precise_value);
+        if let Ok(exact) = try_words(precise_value) {
+            assert_eq!(text, exact, "words and try_words disagree on {}", precise_value);
+        }
 
         // Also test with the negative value
         if precise_value != 0.0 {
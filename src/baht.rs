@@ -0,0 +1,245 @@
+use core::fmt::{self, Write as _};
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use crate::decimal::Decimal;
+use crate::write::StackStr;
+use crate::BahtText;
+#[cfg(feature = "alloc")]
+use crate::{words_from_satang_i128, BahtTextError};
+
+/// An exact amount of Thai baht, held as a whole number of satang.
+///
+/// Arithmetic is checked and never rounds, so amounts can stay typed until
+/// they are written out. `{}` formats the amount as digits and `{:#}` as Thai
+/// words.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use bahttext::Baht;
+///
+/// let price: Baht = "1,234.50".parse().unwrap();
+/// let total = price.checked_mul(2).unwrap();
+///
+/// assert_eq!(total.satang(), 246_900);
+/// assert_eq!(format!("{}", total), "2469.00");
+/// assert_eq!(format!("{:#}", total), "สองพันสี่ร้อยหกสิบเก้าบาทถ้วน");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Baht(i128);
+
+impl Baht {
+    /// Zero baht.
+    pub const ZERO: Self = Self(0);
+
+    /// Creates an amount from a number of satang.
+    pub const fn from_satang(satang: i128) -> Self {
+        Self(satang)
+    }
+
+    /// Creates an amount from a number of whole baht, if it fits.
+    pub const fn from_baht(baht: i128) -> Option<Self> {
+        match baht.checked_mul(100) {
+            Some(satang) => Some(Self(satang)),
+            None => None,
+        }
+    }
+
+    /// Returns the amount in satang.
+    pub const fn satang(self) -> i128 {
+        self.0
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(satang) => Some(Self(satang)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs`, returning `None` on overflow.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(satang) => Some(Self(satang)),
+            None => None,
+        }
+    }
+
+    /// Multiplies the amount by a whole number, returning `None` on overflow.
+    pub const fn checked_mul(self, rhs: i128) -> Option<Self> {
+        match self.0.checked_mul(rhs) {
+            Some(satang) => Some(Self(satang)),
+            None => None,
+        }
+    }
+
+    /// Negates the amount, returning `None` on overflow.
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.0.checked_neg() {
+            Some(satang) => Some(Self(satang)),
+            None => None,
+        }
+    }
+
+    /// Returns the amount in Thai words, as `{:#}` writes it.
+    #[cfg(feature = "alloc")]
+    pub fn words(self) -> String {
+        words_from_satang_i128(self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for Baht {
+    type Err = BahtTextError;
+
+    /// Parses an amount the way [`words_from`](crate::words_from) reads it,
+    /// rounding to the nearest satang.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::parse(s)?
+            .round()?
+            .to_satang()
+            .map(Self)
+            .ok_or(BahtTextError::AmountTooLarge)
+    }
+}
+
+/// Room for the Thai text of any amount: at most 37 digits of baht, each
+/// with a digit and place word of 27 bytes at most, and the words around them.
+const TEXT_LEN: usize = 1_536;
+
+impl fmt::Display for Baht {
+    /// Writes the digits, or the Thai text with `{:#}`, padded to any width
+    /// given.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let satang = self.0.unsigned_abs();
+        if f.alternate() {
+            // The baht of an i128 fit in 39 digits
+            let mut baht = StackStr::<40>::new();
+            write!(baht, "{}", satang / 100)?;
+            let mut text = StackStr::<TEXT_LEN>::new();
+            BahtText::new().write_parts(
+                &mut text,
+                self.0 < 0,
+                baht.as_str(),
+                (satang % 100) as u8,
+            )?;
+            return f.pad(text.as_str());
+        }
+
+        let minus = if self.0 < 0 { "-" } else { "" };
+        // A sign, 39 digits of baht, the point and two of satang
+        let mut digits = StackStr::<43>::new();
+        write!(digits, "{}{}.{:02}", minus, satang / 100, satang % 100)?;
+        f.pad(digits.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let a = Baht::from_satang(150);
+        let b = Baht::from_baht(2).unwrap();
+
+        assert_eq!(a.checked_add(b), Some(Baht::from_satang(350)));
+        assert_eq!(a.checked_sub(b), Some(Baht::from_satang(-50)));
+        assert_eq!(a.checked_mul(-3), Some(Baht::from_satang(-450)));
+        assert_eq!(a.checked_neg(), Some(Baht::from_satang(-150)));
+
+        let max = Baht::from_satang(i128::MAX);
+        assert_eq!(max.checked_add(a), None);
+        assert_eq!(Baht::from_satang(i128::MIN).checked_sub(a), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(Baht::from_satang(i128::MIN).checked_neg(), None);
+        assert_eq!(Baht::from_baht(i128::MAX), None);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut amounts = [Baht::from_satang(100), Baht::from_satang(-5), Baht::ZERO];
+        amounts.sort();
+        assert_eq!(
+            amounts,
+            [Baht::from_satang(-5), Baht::ZERO, Baht::from_satang(100)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_str() {
+        let cases = [
+            ("0", 0),
+            ("1,234.56", 123_456),
+            ("-0.005", -1),
+            ("-0.001", 0),
+            (" 1e3 ", 100_000),
+        ];
+
+        for &(input, satang) in &cases {
+            assert_eq!(
+                input.parse::<Baht>(),
+                Ok(Baht::from_satang(satang)),
+                "Fail case: {}",
+                input
+            );
+        }
+
+        assert!(matches!(
+            "abc".parse::<Baht>(),
+            Err(BahtTextError::ParseError(_))
+        ));
+        assert_eq!("1e40".parse::<Baht>(), Err(BahtTextError::AmountTooLarge));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_display() {
+        let cases = [
+            (0, "0.00", "ศูนย์บาทถ้วน"),
+            (5, "0.05", "ศูนย์บาทห้าสตางค์"),
+            (-150, "-1.50", "ลบหนึ่งบาทห้าสิบสตางค์"),
+            (2_100, "21.00", "ยี่สิบเอ็ดบาทถ้วน"),
+        ];
+
+        for &(satang, digits, words) in &cases {
+            let amount = Baht::from_satang(satang);
+            assert_eq!(amount.to_string(), digits);
+            assert_eq!(format!("{:#}", amount), words);
+            assert_eq!(amount.to_string().parse(), Ok(amount));
+        }
+
+        let min = Baht::from_satang(i128::MIN);
+        assert_eq!(min.to_string().parse(), Ok(min));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_display_padding() {
+        let amount = Baht::from_satang(150);
+        assert_eq!(format!("{:>8}", amount), "    1.50");
+        assert_eq!(format!("{:*<8}", amount), "1.50****");
+        assert_eq!(format!("{:^7}", Baht::from_satang(-5)), " -0.05 ");
+        assert_eq!(format!("{:>#12}", amount), "หนึ่งบาทห้าสิบสตางค์");
+        assert_eq!(format!("{:>#24}", amount), "    หนึ่งบาทห้าสิบสตางค์");
+
+        // The longest texts still fit the buffer
+        for satang in [
+            i128::MIN,
+            i128::MAX,
+            -77_777_777_777_777_777_777_777_777_777_777_777_777,
+        ] {
+            let amount = Baht::from_satang(satang);
+            assert_eq!(format!("{:#}", amount), amount.words());
+            assert_eq!(format!("{:1}", amount), amount.to_string());
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Adds a field of Thai text to each object in a stream of JSON lines.
+
+use std::io::{BufRead, Write};
+
+use bahttext::BahtTextError;
+use serde_json::Value;
+
+use crate::Failure;
+
+/// Which field holds the amount and where its text goes.
+pub(crate) struct JsonLines<'a> {
+    /// A JSON pointer such as `/invoice/total`
+    pub(crate) pointer: &'a str,
+    /// The name of the added field, by default the amount's name with `_text`
+    pub(crate) output_field: Option<&'a str>,
+    /// Stop at the first line that fails instead of passing it through unchanged
+    pub(crate) fail_fast: bool,
+}
+
+impl JsonLines<'_> {
+    /// Copies `input` to `output` with the text added next to the amount on
+    /// every line.
+    ///
+    /// Numbers are converted from their JSON text, so `1234.565` is rounded
+    /// as written rather than as the nearest `f64`. Lines that cannot be
+    /// converted are reported on stderr with their line number and written
+    /// out unchanged; the run still fails at the end.
+    pub(crate) fn convert<R, W, F>(
+        &self,
+        input: R,
+        mut output: W,
+        convert: F,
+    ) -> Result<(), Failure>
+    where
+        R: BufRead,
+        W: Write,
+        F: Fn(&str) -> Result<String, BahtTextError>,
+    {
+        let (parent, key) = self.split_pointer()?;
+        let name = match self.output_field {
+            Some(name) => name.to_string(),
+            None => format!("{}_text", key),
+        };
+
+        let mut failed = 0;
+        for (index, line) in input.lines().enumerate() {
+            let line = line?;
+            let number = index as u64 + 1;
+            if line.trim().is_empty() {
+                writeln!(output)?;
+                continue;
+            }
+
+            match self.convert_line(&line, parent, &key, &name, &convert) {
+                Ok(converted) => writeln!(output, "{}", converted)?,
+                Err(message) if self.fail_fast => {
+                    output.flush()?;
+                    return Err(Failure::Row(number, message));
+                }
+                Err(message) => {
+                    eprintln!("bahttext: line {}: {}", number, message);
+                    failed += 1;
+                    writeln!(output, "{}", line)?;
+                }
+            }
+        }
+
+        output.flush()?;
+        match failed {
+            0 => Ok(()),
+            failed => Err(Failure::Rows(failed)),
+        }
+    }
+
+    fn convert_line<F>(
+        &self,
+        line: &str,
+        parent: &str,
+        key: &str,
+        name: &str,
+        convert: &F,
+    ) -> Result<String, String>
+    where
+        F: Fn(&str) -> Result<String, BahtTextError>,
+    {
+        let mut value: Value =
+            serde_json::from_str(line).map_err(|err| format!("invalid JSON: {}", err))?;
+        let missing = || format!("no field {}", self.pointer);
+        let object = value
+            .pointer_mut(parent)
+            .and_then(Value::as_object_mut)
+            .ok_or_else(missing)?;
+
+        let amount = match object.get(key) {
+            Some(Value::Number(number)) => number.to_string(),
+            Some(Value::String(string)) => string.clone(),
+            Some(_) => return Err(format!("{} is not a number or string", self.pointer)),
+            None => return Err(missing()),
+        };
+        let text = convert(&amount).map_err(|err| format!("\"{}\": {}", amount, err))?;
+
+        object.insert(name.to_string(), Value::String(text));
+        Ok(value.to_string())
+    }
+
+    /// Splits the pointer into the pointer to the enclosing object and the
+    /// unescaped name of the amount's field.
+    fn split_pointer(&self) -> Result<(&str, String), Failure> {
+        match self.pointer.rfind('/') {
+            Some(slash) if self.pointer.starts_with('/') => {
+                let key = &self.pointer[slash + 1..];
+                Ok((
+                    &self.pointer[..slash],
+                    key.replace("~1", "/").replace("~0", "~"),
+                ))
+            }
+            _ => Err(Failure::Pointer(self.pointer.to_string())),
+        }
+    }
+}
@@ -0,0 +1,278 @@
+//! Converts amounts to Thai baht text from the command line, and back.
+
+mod jsonl;
+mod table;
+
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process::ExitCode;
+
+use bahttext::{Baht, BahtText, BahtTextError, Rounding, Strictness, WholeSuffix};
+use clap::{ArgGroup, Args, Parser, ValueEnum};
+use jsonl::JsonLines;
+use table::Table;
+
+/// Converts amounts to Thai baht text, or Thai text back to amounts.
+///
+/// Amounts are taken from the arguments, or one per line from stdin when
+/// there are none. The first bad input stops the run with its error and a
+/// non-zero exit status.
+///
+/// With --csv or --tsv, stdin is read as a table instead and written back
+/// with the text of one column added as a new last column. Rows that fail
+/// are reported with their line number and left with an empty cell, and
+/// rows wider than the header fail and are left as they are.
+/// --jsonl does the same for JSON lines, adding a field next to the amount.
+#[derive(Debug, Parser)]
+#[command(name = "bahttext", version, about, allow_negative_numbers = true)]
+#[command(group(ArgGroup::new("table").args(["csv", "tsv"])))]
+struct Cli {
+    /// Amounts such as 1,234.56, or Thai text with --parse
+    inputs: Vec<String>,
+
+    /// Read Thai text back into amounts instead
+    #[arg(short, long)]
+    parse: bool,
+
+    /// Also accept equivalent spellings when reading Thai text
+    #[arg(long, requires = "parse")]
+    lenient: bool,
+
+    /// Read CSV from stdin and convert COLUMN, given by header name or number
+    #[arg(long, value_name = "COLUMN", conflicts_with = "inputs")]
+    csv: Option<String>,
+
+    /// Read tab-separated values from stdin and convert COLUMN
+    #[arg(long, value_name = "COLUMN", conflicts_with_all = ["inputs", "csv"])]
+    tsv: Option<String>,
+
+    /// Header of the added column [default: the column's header with _text]
+    #[arg(long, value_name = "NAME", requires = "table")]
+    output_column: Option<String>,
+
+    /// Read JSON lines from stdin and convert the field at POINTER, e.g. /total
+    #[arg(
+        long,
+        value_name = "POINTER",
+        conflicts_with_all = ["inputs", "csv", "tsv"]
+    )]
+    jsonl: Option<String>,
+
+    /// Name of the added field [default: the field's name with _text]
+    #[arg(long, value_name = "NAME", requires = "jsonl")]
+    output_field: Option<String>,
+
+    /// The table has no header row, so COLUMN must be a number
+    #[arg(long, requires = "table")]
+    no_header: bool,
+
+    /// Stop at the first row or line that cannot be converted
+    #[arg(long)]
+    fail_fast: bool,
+
+    #[command(flatten)]
+    style: Style,
+}
+
+/// How amounts are written.
+#[derive(Debug, Args)]
+struct Style {
+    /// Word after whole-baht amounts
+    #[arg(long, value_enum, default_value_t = Suffix::Thuan)]
+    suffix: Suffix,
+
+    /// Word before negative amounts
+    #[arg(long, default_value = "ลบ")]
+    negative_prefix: String,
+
+    /// Leave out หนึ่ง before a leading ร้อย, พัน or ล้าน
+    #[arg(long)]
+    no_leading_one: bool,
+
+    /// Leave out ศูนย์บาท before amounts of satang only
+    #[arg(long)]
+    no_zero_baht: bool,
+
+    /// How amounts with more than two decimals are rounded
+    #[arg(long, value_enum, default_value_t = RoundingMode::HalfUp)]
+    rounding: RoundingMode,
+
+    /// Reject amounts with more than two decimals instead of rounding them
+    #[arg(long)]
+    strict: bool,
+
+    /// Reject amounts more than this many baht either side of zero
+    #[arg(long, value_name = "BAHT")]
+    max_baht: Option<u128>,
+
+    /// Round to 15 significant digits first and leave out ศูนย์บาท before
+    /// satang, modelled on spreadsheet BAHTTEXT but not checked against it
+    #[arg(long)]
+    spreadsheet: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Suffix {
+    /// ถ้วน
+    Thuan,
+    /// ตรง
+    Trong,
+    /// Nothing
+    None,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum RoundingMode {
+    HalfUp,
+    HalfEven,
+    Down,
+    Up,
+    TowardZero,
+    NearestQuarter,
+}
+
+impl Style {
+    fn options(&self) -> BahtText<'_> {
+        let mut options = if self.spreadsheet {
+            BahtText::spreadsheet()
+        } else {
+            BahtText::new()
+        };
+
+        options = options
+            .whole_suffix(match self.suffix {
+                Suffix::Thuan => WholeSuffix::Thuan,
+                Suffix::Trong => WholeSuffix::Trong,
+                Suffix::None => WholeSuffix::Omit,
+            })
+            .negative_prefix(&self.negative_prefix)
+            .leading_one(!self.no_leading_one)
+            .rounding(match self.rounding {
+                RoundingMode::HalfUp => Rounding::HalfUp,
+                RoundingMode::HalfEven => Rounding::HalfEven,
+                RoundingMode::Down => Rounding::Down,
+                RoundingMode::Up => Rounding::Up,
+                RoundingMode::TowardZero => Rounding::TowardZero,
+                RoundingMode::NearestQuarter => Rounding::NearestQuarter,
+            })
+            .strict(self.strict);
+        if self.no_zero_baht {
+            options = options.zero_baht(false);
+        }
+        if let Some(max_baht) = self.max_baht {
+            options = options.max_baht(max_baht);
+        }
+        options
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let options = cli.style.options();
+    let strictness = if cli.lenient {
+        Strictness::Lenient
+    } else {
+        Strictness::Strict
+    };
+
+    let convert = |input: &str| -> Result<String, BahtTextError> {
+        if cli.parse {
+            options
+                .parse_words(input, strictness)
+                .map(|satang| Baht::from_satang(satang).to_string())
+        } else {
+            options.words_from(input)
+        }
+    };
+
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let table = match (&cli.csv, &cli.tsv) {
+        (Some(column), _) => Some((b',', column)),
+        (_, Some(column)) => Some((b'\t', column)),
+        _ => None,
+    };
+
+    let result = if let Some((delimiter, column)) = table {
+        let table = Table {
+            delimiter,
+            column,
+            output_column: cli.output_column.as_deref(),
+            has_headers: !cli.no_header,
+            fail_fast: cli.fail_fast,
+        };
+        table.convert(io::stdin().lock(), &mut out, convert)
+    } else if let Some(pointer) = &cli.jsonl {
+        let lines = JsonLines {
+            pointer,
+            output_field: cli.output_field.as_deref(),
+            fail_fast: cli.fail_fast,
+        };
+        lines.convert(io::stdin().lock(), &mut out, convert)
+    } else if cli.inputs.is_empty() {
+        run(io::stdin().lock().lines(), &mut out, convert)
+    } else {
+        run(cli.inputs.iter().cloned().map(Ok), &mut out, convert)
+    };
+
+    match result.and_then(|()| out.flush().map_err(Failure::Io)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => {
+            // Whatever converted before the failure still goes out
+            let _ = out.flush();
+            eprintln!("bahttext: {}", failure);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Why a run stopped early.
+enum Failure {
+    Io(io::Error),
+    Input(String, BahtTextError),
+    Csv(csv::Error),
+    Column(String),
+    Pointer(String),
+    Row(u64, String),
+    Rows(usize),
+}
+
+impl From<io::Error> for Failure {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<csv::Error> for Failure {
+    fn from(err: csv::Error) -> Self {
+        Self::Csv(err)
+    }
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{}", err),
+            Self::Input(input, err) => write!(f, "\"{}\": {}", input, err),
+            Self::Csv(err) => write!(f, "{}", err),
+            Self::Column(column) => write!(f, "no column \"{}\" in the table", column),
+            Self::Pointer(pointer) => write!(f, "\"{}\" is not a JSON pointer to a field", pointer),
+            Self::Row(line, message) => write!(f, "line {}: {}", line, message),
+            Self::Rows(1) => write!(f, "1 row could not be converted"),
+            Self::Rows(rows) => write!(f, "{} rows could not be converted", rows),
+        }
+    }
+}
+
+fn run<I, W, F>(inputs: I, out: &mut W, convert: F) -> Result<(), Failure>
+where
+    I: Iterator<Item = io::Result<String>>,
+    W: Write,
+    F: Fn(&str) -> Result<String, BahtTextError>,
+{
+    for input in inputs {
+        let input = input.map_err(Failure::Io)?;
+        let text = convert(&input).map_err(|err| Failure::Input(input, err))?;
+        writeln!(out, "{}", text).map_err(Failure::Io)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,132 @@
+//! Adds a column of Thai text to a CSV or TSV table.
+
+use std::io;
+
+use bahttext::BahtTextError;
+use csv::{QuoteStyle, ReaderBuilder, StringRecord, WriterBuilder};
+
+use crate::Failure;
+
+/// Where the amounts are in a table and where their text goes.
+pub(crate) struct Table<'a> {
+    pub(crate) delimiter: u8,
+    /// A header name, or a column number counting from 1
+    pub(crate) column: &'a str,
+    /// The header of the added column, by default the source header with `_text`
+    pub(crate) output_column: Option<&'a str>,
+    pub(crate) has_headers: bool,
+    /// Stop at the first row that fails instead of leaving its text empty
+    pub(crate) fail_fast: bool,
+}
+
+impl Table<'_> {
+    /// Copies `input` to `output` with the converted column added to every row.
+    ///
+    /// The text goes in the column after the header's last, or after the
+    /// first row's last without a header. Rows that cannot be converted are
+    /// reported on stderr with their line number and get an empty cell there,
+    /// so one bad amount does not lose the rest of the file; the run still
+    /// fails at the end. Rows wider than the header fail the same way and are
+    /// written unchanged, as they have no room for the text in its column.
+    pub(crate) fn convert<R, W, F>(&self, input: R, output: W, convert: F) -> Result<(), Failure>
+    where
+        R: io::Read,
+        W: io::Write,
+        F: Fn(&str) -> Result<String, BahtTextError>,
+    {
+        // Tab-separated values have no quoting, so quotes are part of a field
+        let tsv = self.delimiter == b'\t';
+        let mut reader = ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(self.has_headers)
+            .quoting(!tsv)
+            .flexible(true)
+            .from_reader(input);
+        let mut writer = WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .quote_style(if tsv {
+                QuoteStyle::Never
+            } else {
+                QuoteStyle::Necessary
+            })
+            .flexible(true)
+            .from_writer(output);
+
+        let (index, mut width) = if self.has_headers {
+            let mut headers = reader.headers()?.clone();
+            let index = self.find_column(&headers)?;
+            let name = match self.output_column {
+                Some(name) => name.to_string(),
+                None => format!("{}_text", &headers[index]),
+            };
+            let width = headers.len();
+            headers.push_field(&name);
+            writer.write_record(&headers)?;
+            (index, Some(width))
+        } else {
+            let index = self.find_column(&StringRecord::new())?;
+            (index, None)
+        };
+
+        let mut failed = 0;
+        let mut record = StringRecord::new();
+        while reader.read_record(&mut record)? {
+            let line = record.position().map_or(0, |position| position.line());
+            let width = *width.get_or_insert(record.len().max(index + 1));
+            let wide = record.len() > width;
+            let text = match record.get(index) {
+                _ if wide => Err(format!(
+                    "{} columns, more than the {} of the {}",
+                    record.len(),
+                    width,
+                    if self.has_headers {
+                        "header"
+                    } else {
+                        "first row"
+                    }
+                )),
+                Some(amount) => convert(amount).map_err(|err| format!("\"{}\": {}", amount, err)),
+                None => Err(format!("no column {}", index + 1)),
+            };
+
+            while record.len() < width {
+                record.push_field("");
+            }
+            match text {
+                Ok(text) => record.push_field(&text),
+                Err(message) if self.fail_fast => {
+                    writer.flush()?;
+                    return Err(Failure::Row(line, message));
+                }
+                Err(message) => {
+                    eprintln!("bahttext: line {}: {}", line, message);
+                    failed += 1;
+                    if !wide {
+                        record.push_field("");
+                    }
+                }
+            }
+            writer.write_record(&record)?;
+        }
+
+        writer.flush()?;
+        match failed {
+            0 => Ok(()),
+            failed => Err(Failure::Rows(failed)),
+        }
+    }
+
+    /// Finds the column by header name, or else by its number from 1.
+    fn find_column(&self, headers: &StringRecord) -> Result<usize, Failure> {
+        if let Some(index) = headers.iter().position(|header| header == self.column) {
+            return Ok(index);
+        }
+
+        match self.column.parse::<usize>() {
+            Ok(number) if number >= 1 && (!self.has_headers || number <= headers.len()) => {
+                Ok(number - 1)
+            }
+            _ => Err(Failure::Column(self.column.to_string())),
+        }
+    }
+}
@@ -0,0 +1,513 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{BahtTextError, Rounding};
+
+/// The largest exponent accepted in text, so that `"1e999999999"` cannot
+/// exhaust memory.
+const MAX_EXPONENT: u64 = 4_096;
+
+/// An amount rounded to whole baht and satang.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Amount {
+    pub(crate) negative: bool,
+    /// The whole baht as ASCII digits without leading zeros, or `"0"`.
+    pub(crate) baht: String,
+    pub(crate) satang: u8,
+}
+
+impl Amount {
+    /// Creates an amount, dropping the sign of zero as rounding does.
+    pub(crate) fn new(negative: bool, baht: u128, satang: u8) -> Self {
+        let mut amount = Self {
+            negative,
+            baht: baht.to_string(),
+            satang,
+        };
+        amount.negative &= !amount.is_zero();
+        amount
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.baht == "0" && self.satang == 0
+    }
+
+    /// Returns the amount in satang, if it fits in an `i128`.
+    pub(crate) fn to_satang(&self) -> Option<i128> {
+        let satang = self
+            .baht
+            .parse::<u128>()
+            .ok()?
+            .checked_mul(100)?
+            .checked_add(self.satang.into())?;
+        signed(self.negative, satang)
+    }
+
+    /// Returns whether the amount is more than `max_baht` either side of zero.
+    pub(crate) fn exceeds(&self, max_baht: u128) -> bool {
+        match self.baht.parse::<u128>() {
+            Ok(baht) => baht > max_baht || (baht == max_baht && self.satang > 0),
+            Err(_) => true,
+        }
+    }
+}
+
+/// A decimal number exactly as written: `digits × 10^-scale`, with leading
+/// zeros dropped from `digits`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Decimal {
+    negative: bool,
+    digits: Vec<u8>,
+    scale: i64,
+}
+
+impl Decimal {
+    /// Parses a decimal literal such as `"-1,234.56"` or `"1.5e3"`.
+    ///
+    /// Thousand separators and surrounding whitespace are ignored, like
+    /// `words_from` always did.
+    pub(crate) fn parse(input: &str) -> Result<Self, BahtTextError> {
+        let cleaned = input.trim().replace(',', "");
+        if cleaned.is_empty() {
+            return Err(BahtTextError::ParseError(
+                "cannot parse amount from empty string".to_string(),
+            ));
+        }
+
+        let (negative, unsigned) = match cleaned.as_bytes()[0] {
+            b'-' => (true, &cleaned[1..]),
+            b'+' => (false, &cleaned[1..]),
+            _ => (false, cleaned.as_str()),
+        };
+
+        if ["inf", "infinity", "nan"]
+            .iter()
+            .any(|word| unsigned.eq_ignore_ascii_case(word))
+        {
+            return Err(BahtTextError::InvalidNumber);
+        }
+
+        let invalid = || BahtTextError::ParseError("invalid amount literal".to_string());
+
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(i) => (
+                &unsigned[..i],
+                parse_exponent(&unsigned[i + 1..]).ok_or_else(invalid)?,
+            ),
+            None => (unsigned, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(invalid());
+        }
+        if !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let digits = integer
+            .bytes()
+            .chain(fraction.bytes())
+            .skip_while(|&b| b == b'0')
+            .map(|b| b - b'0')
+            .collect();
+        let scale = (fraction.len() as i64).saturating_sub(exponent);
+
+        let decimal = Self {
+            negative,
+            digits,
+            scale,
+        };
+        if decimal.exponent_zeros() > MAX_EXPONENT {
+            return Err(BahtTextError::AmountTooLarge);
+        }
+        Ok(decimal)
+    }
+
+    /// Creates a decimal from the ASCII digits of its magnitude and its scale,
+    /// as other decimal types hold them.
+    #[cfg(any(
+        feature = "rust_decimal",
+        feature = "bigdecimal",
+        feature = "num-bigint"
+    ))]
+    pub(crate) fn from_digits(negative: bool, digits: &str, scale: i64) -> Self {
+        Self {
+            negative,
+            digits: digits
+                .bytes()
+                .skip_while(|&b| b == b'0')
+                .map(|b| b - b'0')
+                .collect(),
+            scale,
+        }
+    }
+
+    /// Returns the number of zeros the scale adds after the digits.
+    fn exponent_zeros(&self) -> u64 {
+        match self.digits.is_empty() || self.scale >= 0 {
+            true => 0,
+            false => self.scale.unsigned_abs(),
+        }
+    }
+
+    /// Returns the number of digits before the decimal point, leading zeros
+    /// aside.
+    pub(crate) fn integer_digits(&self) -> u64 {
+        (self.digits.len() as i64).saturating_sub(self.scale).max(0) as u64
+    }
+
+    /// Returns the number of decimal places, not counting trailing zeros.
+    pub(crate) fn decimals(&self) -> u64 {
+        let trailing_zeros = self.digits.iter().rev().take_while(|&&d| d == 0).count();
+        match self.digits.len() - trailing_zeros {
+            0 => 0,
+            _ => self.scale.saturating_sub(trailing_zeros as i64).max(0) as u64,
+        }
+    }
+
+    /// Rounds to `significant` significant digits, with halves rounded away
+    /// from zero, the way a spreadsheet holds every number.
+    pub(crate) fn round_significant(mut self, significant: usize) -> Self {
+        if self.digits.len() <= significant {
+            return self;
+        }
+
+        let round_up = self.digits[significant] >= 5;
+        let dropped = self.digits.len() - significant;
+        self.digits.truncate(significant);
+        self.scale = self.scale.saturating_sub(dropped as i64);
+        if round_up {
+            match self.digits.iter().rposition(|&d| d < 9) {
+                Some(i) => {
+                    self.digits[i] += 1;
+                    self.digits.truncate(i + 1);
+                    self.scale = self.scale.saturating_sub((significant - i - 1) as i64);
+                }
+                None => {
+                    self.digits = vec![1];
+                    self.scale = self.scale.saturating_sub(significant as i64);
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns whether any digit weighing less than `10^power` is not zero.
+    fn has_digits_below(&self, power: i64) -> bool {
+        let below = power
+            .saturating_add(self.scale)
+            .clamp(0, self.digits.len() as i64);
+        self.digits[self.digits.len() - below as usize..]
+            .iter()
+            .any(|&d| d != 0)
+    }
+
+    /// Returns the digit whose weight is `10^power`.
+    fn digit(&self, power: i64) -> u8 {
+        let from_right = power.saturating_add(self.scale);
+        if from_right < 0 || from_right >= self.digits.len() as i64 {
+            return 0;
+        }
+        self.digits[self.digits.len() - 1 - from_right as usize]
+    }
+
+    /// Rounds to the nearest satang, with halves rounded away from zero.
+    pub(crate) fn round(&self) -> Result<Amount, BahtTextError> {
+        self.round_with(Rounding::HalfUp)
+    }
+
+    /// Rounds to whole satang as `rounding` says.
+    ///
+    /// The whole baht are kept as digits, so there is no upper bound: the
+    /// zeros of an exponent are written out, which text input limits to
+    /// `MAX_EXPONENT` when it is parsed.
+    ///
+    /// # Errors
+    /// Returns `BahtTextError::AmountTooLarge` if the whole baht have more
+    /// digits than a `Vec` can hold
+    pub(crate) fn round_with(&self, rounding: Rounding) -> Result<Amount, BahtTextError> {
+        let integer = match usize::try_from(self.scale) {
+            Ok(scale) => &self.digits[..self.digits.len().saturating_sub(scale)],
+            Err(_) => &self.digits[..],
+        };
+        let trailing_zeros = usize::try_from(self.exponent_zeros())
+            .ok()
+            .filter(|zeros| {
+                zeros
+                    .checked_add(integer.len())
+                    .is_some_and(|len| len <= isize::MAX as usize)
+            })
+            .ok_or(BahtTextError::AmountTooLarge)?;
+
+        let mut baht: Vec<u8> = integer
+            .iter()
+            .map(|d| b'0' + d)
+            .chain(core::iter::repeat_n(b'0', trailing_zeros))
+            .collect();
+        if baht.is_empty() {
+            baht.push(b'0');
+        }
+
+        let mut satang = rounding.round_satang(
+            self.negative,
+            self.digit(-1) * 10 + self.digit(-2),
+            self.digit(-3),
+            self.has_digits_below(-3),
+        );
+        if satang == 100 {
+            satang = 0;
+            increment(&mut baht);
+        }
+
+        let mut amount = Amount {
+            negative: self.negative,
+            baht: String::from_utf8(baht).expect("digits are ASCII"),
+            satang,
+        };
+        amount.negative &= !amount.is_zero();
+        Ok(amount)
+    }
+}
+
+/// Applies a sign to a magnitude, if the result fits in an `i128`.
+pub(crate) fn signed(negative: bool, magnitude: u128) -> Option<i128> {
+    match negative {
+        true => 0i128.checked_sub_unsigned(magnitude),
+        false => i128::try_from(magnitude).ok(),
+    }
+}
+
+/// Adds one to a non-empty string of ASCII digits.
+fn increment(digits: &mut Vec<u8>) {
+    for digit in digits.iter_mut().rev() {
+        if *digit == b'9' {
+            *digit = b'0';
+        } else {
+            *digit += 1;
+            return;
+        }
+    }
+    digits.insert(0, b'1');
+}
+
+/// Parses an optionally signed exponent, saturating on overflow.
+fn parse_exponent(s: &str) -> Option<i64> {
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let magnitude = digits.bytes().fold(0i64, |acc, b| {
+        acc.saturating_mul(10).saturating_add((b - b'0').into())
+    });
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round(input: &str) -> Amount {
+        Decimal::parse(input).unwrap().round().unwrap()
+    }
+
+    #[test]
+    fn test_round_half_away_from_zero() {
+        let cases = [
+            ("0", false, 0, 0),
+            ("1.005", false, 1, 1),
+            ("1.004999", false, 1, 0),
+            ("-51.995", true, 52, 0),
+            ("-0.001", false, 0, 0),
+            ("9.999", false, 10, 0),
+            (".5", false, 0, 50),
+            ("5.", false, 5, 0),
+            ("1.5e3", false, 1500, 0),
+            ("125e-2", false, 1, 25),
+            ("1e-300", false, 0, 0),
+            (
+                "92,233,720,368,547,758.07",
+                false,
+                92_233_720_368_547_758,
+                7,
+            ),
+        ];
+
+        for &(input, negative, baht, satang) in &cases {
+            assert_eq!(
+                round(input),
+                Amount::new(negative, baht, satang),
+                "Fail case: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_modes() {
+        use Rounding::*;
+
+        let cases = [
+            ("1.125", HalfEven, 1, 12),
+            ("1.135", HalfEven, 1, 14),
+            ("1.1250001", HalfEven, 1, 13),
+            ("-1.125", HalfEven, 1, 12),
+            ("1.125", HalfUp, 1, 13),
+            ("1.121", Up, 1, 13),
+            ("1.12", Up, 1, 12),
+            ("1.1200000001", Up, 1, 13),
+            ("-1.129", Up, 1, 12),
+            ("1.129", Down, 1, 12),
+            ("-1.121", Down, 1, 13),
+            ("-1.1200000001", Down, 1, 13),
+            ("-1.129", TowardZero, 1, 12),
+            ("9.999", TowardZero, 9, 99),
+            ("99.991", Up, 100, 0),
+            ("1.12", NearestQuarter, 1, 0),
+            ("1.125", NearestQuarter, 1, 25),
+            ("1.37", NearestQuarter, 1, 25),
+            ("1.375", NearestQuarter, 1, 50),
+            ("-1.874", NearestQuarter, 1, 75),
+            ("1.875", NearestQuarter, 2, 0),
+        ];
+
+        for &(input, rounding, baht, satang) in &cases {
+            let amount = Decimal::parse(input).unwrap().round_with(rounding).unwrap();
+            assert_eq!(
+                (amount.baht.as_str(), amount.satang),
+                (baht.to_string().as_str(), satang),
+                "Fail case: {} {:?}",
+                input,
+                rounding
+            );
+            assert_eq!(
+                amount.negative,
+                input.starts_with('-'),
+                "Fail case: {}",
+                input
+            );
+        }
+
+        let zero = Decimal::parse("-0.001").unwrap();
+        assert!(zero.round_with(Rounding::Down).unwrap().negative);
+        assert!(!zero.round_with(Rounding::Up).unwrap().negative);
+    }
+
+    #[test]
+    fn test_round_beyond_u128() {
+        let max = u128::MAX.to_string();
+        assert_eq!(round(&max).baht, max);
+        assert_eq!(
+            round(&format!("{}.995", max)).baht,
+            "340282366920938463463374607431768211456"
+        );
+        assert_eq!(
+            round("999999999999999999999999999999999999999.995").baht,
+            format!("1{}", "0".repeat(39))
+        );
+
+        let digits = "1234567890".repeat(50);
+        assert_eq!(
+            round(&format!("-{}.125", digits)),
+            Amount {
+                negative: true,
+                baht: digits,
+                satang: 13,
+            }
+        );
+
+        assert_eq!(round("1e4096").baht.len(), 4_097);
+        assert_eq!(round("0e99999999999999999999").baht, "0");
+        assert_eq!(Decimal::parse("1e4097"), Err(BahtTextError::AmountTooLarge));
+        assert_eq!(
+            Decimal::parse("-1e99999999999999999999"),
+            Err(BahtTextError::AmountTooLarge)
+        );
+        assert_eq!(Decimal::parse("1e-4097").unwrap().integer_digits(), 0);
+        assert_eq!(Decimal::parse("12.5e4096").unwrap().integer_digits(), 4_098);
+    }
+
+    #[test]
+    fn test_round_significant() {
+        let cases = [
+            ("1234567890123456.78", "1234567890123460"),
+            ("0.1249999999999999", "0.13"),
+            ("-99999999999999999", "-100000000000000000"),
+            ("9.999999999999999e3", "10000"),
+            ("0.004999999999999999", "0.01"),
+            ("1.005", "1.01"),
+        ];
+
+        for &(input, expected) in &cases {
+            assert_eq!(
+                Decimal::parse(input).unwrap().round_significant(15).round(),
+                Decimal::parse(expected).unwrap().round(),
+                "Fail case: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_decimals() {
+        let cases = [
+            ("12.345", 3),
+            ("12.3450", 3),
+            ("12.30", 1),
+            ("1,200", 0),
+            ("0.000", 0),
+            ("1.2345e2", 2),
+            ("1e-300", 300),
+            ("0e-300", 0),
+        ];
+
+        for &(input, decimals) in &cases {
+            assert_eq!(
+                Decimal::parse(input).unwrap().decimals(),
+                decimals,
+                "Fail case: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_exceeds() {
+        assert!(!Amount::new(false, 100, 0).exceeds(100));
+        assert!(Amount::new(false, 100, 1).exceeds(100));
+        assert!(!Amount::new(false, u128::MAX, 0).exceeds(u128::MAX));
+        assert!(round("340282366920938463463374607431768211456").exceeds(u128::MAX));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for input in [
+            "", " ", "-", ".", "1.2.3", "1e", "e5", "1e+", "abc", "--1", "1 000",
+        ] {
+            assert!(
+                matches!(Decimal::parse(input), Err(BahtTextError::ParseError(_))),
+                "Should return ParseError for {:?}",
+                input
+            );
+        }
+
+        for input in ["inf", "-Infinity", "NaN", "+nan"] {
+            assert_eq!(
+                Decimal::parse(input),
+                Err(BahtTextError::InvalidNumber),
+                "Should return InvalidNumber for {:?}",
+                input
+            );
+        }
+    }
+}
@@ -1,5 +1,42 @@
-use std::error::Error;
-use std::fmt;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::error::Error;
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+mod baht;
+#[cfg(feature = "alloc")]
+mod decimal;
+mod options;
+#[cfg(feature = "alloc")]
+mod parse;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "alloc")]
+mod to_baht_text;
+mod write;
+
+#[cfg(feature = "alloc")]
+use decimal::{Amount, Decimal};
+#[cfg(feature = "alloc")]
+use options::satang_to_amount;
+
+pub use baht::Baht;
+#[cfg(feature = "alloc")]
+pub use options::CashAmount;
+pub use options::{BahtText, Rounding, WholeSuffix};
+#[cfg(feature = "alloc")]
+pub use parse::{parse_words, parse_words_with, verify_words, Strictness, Verification};
+#[cfg(feature = "alloc")]
+pub use to_baht_text::ToBahtText;
+#[cfg(feature = "std")]
+pub use write::write_words_io;
+pub use write::{words_into, words_len, write_number_words, write_words};
 
 const UNIT_WORDS: [&str; 10] = [
     "",
@@ -16,44 +53,239 @@ const UNIT_WORDS: [&str; 10] = [
 
 const UNIT_PLACES: [&str; 7] = ["", "สิบ", "ร้อย", "พัน", "หมื่น", "แสน", "ล้าน"];
 
+/// Converts a monetary amount to Thai text representation.
+///
+/// The amount is read through its shortest decimal representation and rounded
+/// to the nearest satang, halves away from zero. This never fails: NaN reads as
+/// zero and infinities saturate to `f64::MAX`, like an `as` cast. Use
+/// [`try_words`] to detect those instead.
+///
+/// # Examples
+/// ```
+/// use bahttext::words;
+///
+/// assert_eq!(words(1_234.56), "หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์");
+/// ```
+#[cfg(feature = "alloc")]
 pub fn words(money: f64) -> String {
-    let minus = if money.is_sign_negative() {
-        "ลบ"
-    } else {
-        ""
-    };
+    let mut text = String::new();
+    write_words(&mut text, money).expect("a String can always be written to");
+    text
+}
+
+/// Converts a whole number to Thai words, without บาท, สตางค์ or ถ้วน.
+///
+/// For quantities, page counts and the like. Negative numbers start with ลบ.
+///
+/// # Examples
+/// ```
+/// use bahttext::number_words;
+///
+/// assert_eq!(number_words(1_234), "หนึ่งพันสองร้อยสามสิบสี่");
+/// assert_eq!(number_words(-21), "ลบยี่สิบเอ็ด");
+/// assert_eq!(number_words(0), "ศูนย์");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn number_words(number: i128) -> String {
+    let mut text = String::new();
+    write_number_words(&mut text, number).expect("a String can always be written to");
+    text
+}
+
+/// Converts a monetary amount to Thai text representation, checking that it can be read.
+///
+/// # Errors
+/// Returns `BahtTextError::InvalidNumber` if the amount is NaN or infinite
+///
+/// # Examples
+/// ```
+/// use bahttext::{try_words, BahtTextError};
+///
+/// assert_eq!(try_words(1e30).unwrap(), "หนึ่งล้านล้านล้านล้านล้านบาทถ้วน");
+/// assert_eq!(try_words(f64::NAN).unwrap_err(), BahtTextError::InvalidNumber);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn try_words(money: f64) -> Result<String, BahtTextError> {
+    BahtText::new().try_words(money)
+}
 
-    let precise_amount = (money.abs() * 100.0).round() / 100.0;
-    let whole_baht = precise_amount.trunc() as u64;
-    let satang = ((precise_amount - whole_baht as f64) * 100.0).round() as u64;
+/// Converts a monetary amount to Thai text representation, rejecting amounts above `max_baht`.
+///
+/// # Errors
+/// Returns `BahtTextError::InvalidNumber` if the amount is NaN or infinite,
+/// or `BahtTextError::AmountTooLarge` if it is more than `max_baht` either side of zero
+///
+/// # Examples
+/// ```
+/// use bahttext::{try_words_with_limit, BahtTextError};
+///
+/// assert_eq!(try_words_with_limit(1_000.0, 1_000).unwrap(), "หนึ่งพันบาทถ้วน");
+/// assert_eq!(
+///     try_words_with_limit(1_000.01, 1_000).unwrap_err(),
+///     BahtTextError::AmountTooLarge
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn try_words_with_limit(money: f64, max_baht: u128) -> Result<String, BahtTextError> {
+    BahtText::new().max_baht(max_baht).try_words(money)
+}
+
+/// Rounds a monetary amount to the nearest 25 satang for payment in cash.
+///
+/// Returns the rounded amount in satang with its text, so a receipt can print
+/// the cash total and its words from the same value.
+///
+/// # Errors
+/// Returns `BahtTextError::InvalidNumber` if the amount is NaN or infinite,
+/// or `BahtTextError::AmountTooLarge` if it does not fit in an `i128` number of satang
+///
+/// # Examples
+/// ```
+/// use bahttext::cash_words;
+///
+/// let cash = cash_words(1_234.63).unwrap();
+/// assert_eq!(cash.satang, 123_475);
+/// assert_eq!(cash.words, "หนึ่งพันสองร้อยสามสิบสี่บาทเจ็ดสิบห้าสตางค์");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn cash_words(money: f64) -> Result<CashAmount, BahtTextError> {
+    BahtText::new().cash_words(money)
+}
 
-    let baht_text = money_to_thai_words(whole_baht);
+#[cfg(feature = "alloc")]
+pub(crate) fn f64_to_amount(money: f64) -> Result<Amount, BahtTextError> {
+    f64_to_decimal(money)?.round()
+}
 
-    match satang {
-        0 => format!("{}{}บาทถ้วน", minus, baht_text),
-        _ => format!("{}{}บาท{}สตางค์", minus, baht_text, money_to_thai_words(satang)),
+#[cfg(feature = "alloc")]
+pub(crate) fn f64_to_decimal(money: f64) -> Result<Decimal, BahtTextError> {
+    if !money.is_finite() {
+        return Err(BahtTextError::InvalidNumber);
     }
+
+    Decimal::parse(&money.to_string())
 }
 
-fn money_to_thai_words(money: u64) -> String {
-    if money == 0 {
-        return "ศูนย์".to_string();
+/// Converts an exact amount of whole baht and satang to Thai text representation.
+///
+/// No floating-point arithmetic is involved, so the text always matches the
+/// amount given, however many baht it holds.
+///
+/// # Arguments
+/// * `negative` - Whether the amount is below zero
+/// * `baht` - The whole baht
+/// * `satang` - The satang, from 0 to 99
+///
+/// # Errors
+/// Returns `BahtTextError::InvalidNumber` if `satang` is greater than 99
+///
+/// # Examples
+/// ```
+/// use bahttext::words_exact;
+///
+/// let result = words_exact(false, 1_234_567_890_123, 45).unwrap();
+/// assert_eq!(
+///     result,
+///     "หนึ่งล้านสองแสนสามหมื่นสี่พันห้าร้อยหกสิบเจ็ดล้านแปดแสนเก้าหมื่นหนึ่งร้อยยี่สิบสามบาทสี่สิบห้าสตางค์"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn words_exact(negative: bool, baht: u128, satang: u8) -> Result<String, BahtTextError> {
+    BahtText::new().words_exact(negative, baht, satang)
+}
+
+/// Converts an amount in satang to Thai text representation.
+///
+/// Payment systems usually hold THB as an integer number of satang; this reads
+/// it directly, with the same output as [`words`] and no round-trip through `f64`.
+///
+/// # Examples
+/// ```
+/// use bahttext::words_from_satang;
+///
+/// assert_eq!(words_from_satang(123_456), "หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์");
+/// assert_eq!(words_from_satang(-50), "ลบศูนย์บาทห้าสิบสตางค์");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn words_from_satang(satang: i64) -> String {
+    satang_to_words(satang < 0, satang.unsigned_abs().into())
+}
+
+/// Converts an unsigned amount in satang to Thai text representation.
+///
+/// See [`words_from_satang`].
+#[cfg(feature = "alloc")]
+pub fn words_from_satang_u64(satang: u64) -> String {
+    satang_to_words(false, satang.into())
+}
+
+/// Converts an amount in satang held in an `i128` to Thai text representation.
+///
+/// See [`words_from_satang`].
+#[cfg(feature = "alloc")]
+pub fn words_from_satang_i128(satang: i128) -> String {
+    satang_to_words(satang < 0, satang.unsigned_abs())
+}
+
+/// Converts an unsigned amount in satang held in a `u128` to Thai text representation.
+///
+/// See [`words_from_satang`].
+#[cfg(feature = "alloc")]
+pub fn words_from_satang_u128(satang: u128) -> String {
+    satang_to_words(false, satang)
+}
+
+#[cfg(feature = "alloc")]
+fn satang_to_words(negative: bool, satang: u128) -> String {
+    BahtText::new().amount_words(&satang_to_amount(negative, satang))
+}
+
+/// Writes a string of ASCII digits of any length in Thai.
+///
+/// Every six digits from the right form a group closed by ล้าน, so
+/// 10^12 reads as หนึ่งล้านล้าน. The groups are walked left to right rather
+/// than by recursing on the millions, which keeps the stack flat for very
+/// long inputs. Leading zeros are skipped. With `leading_one` false, หนึ่ง is
+/// left out before the leading place, as in ร้อย or ล้าน.
+pub(crate) fn write_number<W: fmt::Write>(
+    w: &mut W,
+    digits: &str,
+    leading_one: bool,
+) -> fmt::Result {
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return w.write_str("ศูนย์");
     }
 
-    let mut baht_text = String::with_capacity(128);
-    let mut amount = money;
+    let (head, tail) = digits.split_at(match digits.len() % 6 {
+        0 => 6,
+        n => n,
+    });
 
-    if amount >= 1_000_000 {
-        let millions = amount / 1_000_000;
-        amount %= 1_000_000;
-        baht_text.push_str(&money_to_thai_words(millions));
-        baht_text.push_str(UNIT_PLACES[6]);
+    if leading_one || head != "1" || tail.is_empty() {
+        write_group(w, head, false, leading_one)?;
+    }
+    for group in tail.as_bytes().chunks(6) {
+        w.write_str(UNIT_PLACES[6])?;
+        write_group(w, core::str::from_utf8(group).unwrap(), true, true)?;
     }
 
-    let s = amount.to_string();
-    let len_s = s.len();
+    Ok(())
+}
 
-    for (i, char) in s.chars().enumerate() {
+/// Writes up to six digits in Thai.
+///
+/// `wrote` tells whether any digits were written already, in which case a
+/// units digit of one is written เอ็ด.
+fn write_group<W: fmt::Write>(
+    w: &mut W,
+    group: &str,
+    mut wrote: bool,
+    leading_one: bool,
+) -> fmt::Result {
+    let len_s = group.len();
+
+    for (i, char) in group.chars().enumerate() {
         let digit = char.to_digit(10).unwrap() as usize;
         let place = len_s - i - 1;
 
@@ -62,30 +294,54 @@ fn money_to_thai_words(money: u64) -> String {
         }
 
         match (digit, place) {
-            (2, 1) => baht_text.push_str("ยี่สิบ"),
-            (1, 1) => baht_text.push_str(UNIT_PLACES[1]),
-            (1, 0) if !baht_text.is_empty() => baht_text.push_str("เอ็ด"),
+            (2, 1) => w.write_str("ยี่สิบ")?,
+            (1, 1) => w.write_str(UNIT_PLACES[1])?,
+            (1, 0) if wrote => w.write_str("เอ็ด")?,
+            (1, 2..) if !wrote && !leading_one => w.write_str(UNIT_PLACES[place])?,
             _ => {
-                baht_text.push_str(UNIT_WORDS[digit]);
+                w.write_str(UNIT_WORDS[digit])?;
                 if place > 0 && place < UNIT_PLACES.len() {
-                    baht_text.push_str(UNIT_PLACES[place]);
+                    w.write_str(UNIT_PLACES[place])?;
                 }
             }
         }
+        wrote = true;
     }
 
-    baht_text
+    Ok(())
 }
 
 /// Error types for bahttext operations
+///
+/// `ParseError` only exists with the `alloc` feature, so matches outside this
+/// crate need a wildcard arm to build the same with and without it.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BahtTextError {
     /// Error when parsing string to number fails
+    #[cfg(feature = "alloc")]
     ParseError(String),
     /// Error when the amount is not a number
     InvalidNumber,
     /// Error when the amount is too large
     AmountTooLarge,
+    /// Error when Thai text cannot be read as an amount
+    InvalidWords {
+        /// Byte offset in the text where reading failed
+        position: usize,
+        /// What was expected at that position
+        expected: &'static str,
+    },
+    /// Error when the amount has more decimals than whole satang allow
+    ExcessPrecision {
+        /// The number of decimal places in the amount
+        decimals: u64,
+    },
+    /// Error when the text does not fit in the buffer given
+    BufferTooSmall {
+        /// The number of bytes the text needs
+        required: usize,
+    },
 }
 
 impl Error for BahtTextError {}
@@ -93,9 +349,23 @@ impl Error for BahtTextError {}
 impl fmt::Display for BahtTextError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            #[cfg(feature = "alloc")]
             Self::ParseError(msg) => write!(f, "Failed to parse amount: {}", msg),
             Self::InvalidNumber => write!(f, "Invalid number format"),
             Self::AmountTooLarge => write!(f, "Amount is too large"),
+            Self::InvalidWords { position, expected } => {
+                write!(
+                    f,
+                    "Invalid Thai words at byte {}: expected {}",
+                    position, expected
+                )
+            }
+            Self::ExcessPrecision { decimals } => {
+                write!(f, "Amount has {} decimal places, more than 2", decimals)
+            }
+            Self::BufferTooSmall { required } => {
+                write!(f, "Buffer is too small: {} bytes required", required)
+            }
         }
     }
 }
@@ -105,8 +375,13 @@ impl fmt::Display for BahtTextError {
 /// # Arguments
 /// * `input` - A string slice that holds the monetary amount (e.g., "1,234.56")
 ///
+/// The amount is read as an exact decimal and rounded to the nearest satang,
+/// halves away from zero, without going through `f64`. There is no limit on
+/// the number of digits: every six whole-baht digits add another ล้าน.
+///
 /// # Errors
-/// Returns `BahtTextError` if the input cannot be parsed as a valid number
+/// Returns `BahtTextError` if the input cannot be parsed as a valid number,
+/// or `BahtTextError::AmountTooLarge` if its exponent is above 4096
 ///
 /// # Examples
 /// ```
@@ -114,22 +389,104 @@ impl fmt::Display for BahtTextError {
 ///
 /// let result = words_from("1,234.56").unwrap();
 /// assert_eq!(result, "หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์");
+///
+/// let result = words_from("1000000000000000000000000.50").unwrap();
+/// assert_eq!(result, "หนึ่งล้านล้านล้านล้านบาทห้าสิบสตางค์");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn words_from(input: &str) -> Result<String, BahtTextError> {
-    let cleaned = input.trim().replace(',', "");
-    cleaned.parse::<f64>()
-        .map_err(|e| BahtTextError::ParseError(e.to_string()))
-        .and_then(|n| {
-            if n.is_finite() {
-                Ok(words(n))
-            } else {
-                Err(BahtTextError::InvalidNumber)
-            }
-        })
+    BahtText::new().words_from(input)
 }
 
+/// Converts a string representing a monetary amount to Thai text representation,
+/// rejecting amounts above `max_baht`.
+///
+/// # Errors
+/// Returns `BahtTextError` if the input cannot be parsed as a valid number,
+/// or `BahtTextError::AmountTooLarge` if it is more than `max_baht` either side of zero
+///
+/// # Examples
+/// ```
+/// use bahttext::{words_from_with_limit, BahtTextError};
+///
+/// assert_eq!(
+///     words_from_with_limit("-999,999.99", 1_000_000).unwrap(),
+///     "ลบเก้าแสนเก้าหมื่นเก้าพันเก้าร้อยเก้าสิบเก้าบาทเก้าสิบเก้าสตางค์"
+/// );
+/// assert_eq!(
+///     words_from_with_limit("1,000,000.01", 1_000_000).unwrap_err(),
+///     BahtTextError::AmountTooLarge
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn words_from_with_limit(input: &str, max_baht: u128) -> Result<String, BahtTextError> {
+    BahtText::new().max_baht(max_baht).words_from(input)
+}
 
-#[cfg(test)]
+/// Converts a [`rust_decimal::Decimal`] to Thai text representation without
+/// going through `f64`.
+///
+/// # Errors
+/// Never fails with the default options; see
+/// [`BahtText::words_from_decimal`] for the errors other options can cause
+///
+/// # Examples
+/// ```
+/// use bahttext::words_from_decimal;
+/// use rust_decimal::Decimal;
+///
+/// let amount = Decimal::new(1_234_565, 3);
+/// assert_eq!(
+///     words_from_decimal(amount).unwrap(),
+///     "หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบเจ็ดสตางค์"
+/// );
+/// ```
+#[cfg(feature = "rust_decimal")]
+pub fn words_from_decimal(amount: rust_decimal::Decimal) -> Result<String, BahtTextError> {
+    BahtText::new().words_from_decimal(amount)
+}
+
+/// Converts a [`bigdecimal::BigDecimal`] to Thai text representation, with no
+/// upper bound on its digits.
+///
+/// # Errors
+/// Returns `BahtTextError::AmountTooLarge` only if the whole baht have more
+/// digits than can be held in memory
+///
+/// # Examples
+/// ```
+/// use bahttext::words_from_big_decimal;
+/// use bigdecimal::BigDecimal;
+///
+/// let amount: BigDecimal = "1000000000000000000000000.505".parse().unwrap();
+/// assert_eq!(
+///     words_from_big_decimal(&amount).unwrap(),
+///     "หนึ่งล้านล้านล้านล้านบาทห้าสิบเอ็ดสตางค์"
+/// );
+/// ```
+#[cfg(feature = "bigdecimal")]
+pub fn words_from_big_decimal(amount: &bigdecimal::BigDecimal) -> Result<String, BahtTextError> {
+    BahtText::new().words_from_big_decimal(amount)
+}
+
+/// Converts a [`num_bigint::BigInt`] number of whole baht to Thai text
+/// representation, with no upper bound.
+///
+/// # Examples
+/// ```
+/// use bahttext::words_from_big_int;
+/// use num_bigint::BigInt;
+///
+/// assert_eq!(words_from_big_int(&BigInt::from(-21)), "ลบยี่สิบเอ็ดบาทถ้วน");
+/// ```
+#[cfg(feature = "num-bigint")]
+pub fn words_from_big_int(baht: &num_bigint::BigInt) -> String {
+    BahtText::new()
+        .words_from_big_int(baht)
+        .expect("whole baht never exceed the default limit")
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
 
@@ -151,6 +508,138 @@ mod tests {
         assert_eq!(words_from(" 1234.56 ").unwrap(), "หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์");
     }
 
+    #[test]
+    fn test_words_exact() {
+        assert_eq!(words_exact(false, 0, 0).unwrap(), "ศูนย์บาทถ้วน");
+        assert_eq!(words_exact(false, 0, 50).unwrap(), "ศูนย์บาทห้าสิบสตางค์");
+        assert_eq!(words_exact(true, 100, 0).unwrap(), "ลบหนึ่งร้อยบาทถ้วน");
+        assert_eq!(words_exact(true, 0, 0).unwrap(), words_from("-0").unwrap());
+        assert_eq!(words_exact(true, 0, 0).unwrap(), words(-0.0));
+        assert_eq!(words_exact(false, 21, 1).unwrap(), "ยี่สิบเอ็ดบาทหนึ่งสตางค์");
+        assert_eq!(
+            words_exact(false, u128::MAX, 99).unwrap(),
+            "สามร้อยสี่สิบล้านสองแสนแปดหมื่นสองพันสามร้อยหกสิบหกล้านเก้าแสนสองหมื่นเก้าร้อยสามสิบแปดล้านสี่แสนหกหมื่นสามพันสี่ร้อยหกสิบสามล้านสามแสนเจ็ดหมื่นสี่พันหกร้อยเจ็ดล้านสี่แสนสามหมื่นหนึ่งพันเจ็ดร้อยหกสิบแปดล้านสองแสนหนึ่งหมื่นหนึ่งพันสี่ร้อยห้าสิบห้าบาทเก้าสิบเก้าสตางค์"
+        );
+
+        assert_eq!(
+            words_exact(false, 1, 100).unwrap_err(),
+            BahtTextError::InvalidNumber,
+            "Should return InvalidNumber for satang above 99"
+        );
+    }
+
+    #[test]
+    fn test_words_from_satang() {
+        assert_eq!(words_from_satang(0), "ศูนย์บาทถ้วน");
+        assert_eq!(words_from_satang(1), "ศูนย์บาทหนึ่งสตางค์");
+        assert_eq!(words_from_satang(100), "หนึ่งบาทถ้วน");
+        assert_eq!(words_from_satang(-10_050), "ลบหนึ่งร้อยบาทห้าสิบสตางค์");
+        assert_eq!(
+            words_from_satang(i64::MIN),
+            words_from("-92233720368547758.08").unwrap()
+        );
+        assert_eq!(
+            words_from_satang_u64(u64::MAX),
+            words_from("184467440737095516.15").unwrap()
+        );
+        assert_eq!(
+            words_from_satang_i128(i128::MIN),
+            format!("ลบ{}", words_from_satang_u128(1 << 127))
+        );
+        assert_eq!(
+            words_from_satang_u128(u128::MAX),
+            words_exact(false, u128::MAX / 100, 55).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_number_words() {
+        assert_eq!(number_words(0), "ศูนย์");
+        assert_eq!(number_words(1), "หนึ่ง");
+        assert_eq!(number_words(-1_000_000), "ลบหนึ่งล้าน");
+        assert_eq!(
+            format!("{}บาทถ้วน", number_words(i128::MIN)),
+            words_from(&i128::MIN.to_string()).unwrap()
+        );
+        assert_eq!(
+            format!("{}บาทถ้วน", number_words(i128::MAX)),
+            words_exact(false, i128::MAX as u128, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_try_words() {
+        assert_eq!(try_words(0.0).unwrap(), "ศูนย์บาทถ้วน");
+        assert_eq!(try_words(-0.0).unwrap(), "ศูนย์บาทถ้วน");
+        assert_eq!(try_words(-0.001).unwrap(), "ศูนย์บาทถ้วน");
+        assert_eq!(try_words(1.005).unwrap(), "หนึ่งบาทหนึ่งสตางค์");
+        assert_eq!(try_words(1e30).unwrap(), "หนึ่งล้านล้านล้านล้านล้านบาทถ้วน");
+
+        assert_eq!(
+            try_words(f64::NAN).unwrap_err(),
+            BahtTextError::InvalidNumber
+        );
+        assert_eq!(
+            try_words(f64::INFINITY).unwrap_err(),
+            BahtTextError::InvalidNumber
+        );
+        assert_eq!(
+            try_words(f64::NEG_INFINITY).unwrap_err(),
+            BahtTextError::InvalidNumber
+        );
+
+        assert_eq!(
+            try_words(f64::MAX).unwrap(),
+            words_from(&f64::MAX.to_string()).unwrap()
+        );
+        assert_eq!(try_words(-1e39).unwrap(), "ลบหนึ่งพันล้านล้านล้านล้านล้านล้านบาทถ้วน");
+    }
+
+    #[test]
+    fn test_words_saturates() {
+        assert_eq!(words(f64::NAN), "ศูนย์บาทถ้วน");
+        assert_eq!(words(f64::INFINITY), words(f64::MAX));
+        assert_eq!(words(f64::NEG_INFINITY), words(f64::MIN));
+        assert_eq!(words(f64::MIN), format!("ลบ{}", words(f64::MAX)));
+    }
+
+    #[test]
+    fn test_amount_limit() {
+        assert_eq!(try_words_with_limit(100.0, 100).unwrap(), "หนึ่งร้อยบาทถ้วน");
+        assert_eq!(try_words_with_limit(-100.0, 100).unwrap(), "ลบหนึ่งร้อยบาทถ้วน");
+        assert_eq!(try_words_with_limit(100.004, 100).unwrap(), "หนึ่งร้อยบาทถ้วน");
+        assert_eq!(
+            try_words_with_limit(100.005, 100).unwrap_err(),
+            BahtTextError::AmountTooLarge
+        );
+        assert_eq!(
+            try_words_with_limit(-101.0, 100).unwrap_err(),
+            BahtTextError::AmountTooLarge
+        );
+        assert_eq!(try_words_with_limit(0.0, 0).unwrap(), "ศูนย์บาทถ้วน");
+        assert_eq!(
+            try_words_with_limit(0.01, 0).unwrap_err(),
+            BahtTextError::AmountTooLarge
+        );
+
+        assert_eq!(
+            words_from_with_limit("1,000", 1_000).unwrap(),
+            "หนึ่งพันบาทถ้วน"
+        );
+        assert_eq!(
+            words_from_with_limit("1,000.01", 1_000).unwrap_err(),
+            BahtTextError::AmountTooLarge
+        );
+        assert_eq!(
+            words_from_with_limit("1e39", u128::MAX).unwrap_err(),
+            BahtTextError::AmountTooLarge
+        );
+        assert!(matches!(
+            words_from_with_limit("abc", 1_000).unwrap_err(),
+            BahtTextError::ParseError(_)
+        ));
+    }
+
     #[test]
     fn test_baht_text_error_display() {
         // Test ParseError
@@ -176,6 +665,33 @@ mod tests {
             "Amount is too large",
             "Should format AmountTooLarge correctly"
         );
+
+        // Test InvalidWords
+        let invalid_words = BahtTextError::InvalidWords {
+            position: 27,
+            expected: "บาท",
+        };
+        assert_eq!(
+            invalid_words.to_string(),
+            "Invalid Thai words at byte 27: expected บาท",
+            "Should format InvalidWords correctly"
+        );
+
+        // Test ExcessPrecision
+        let excess_precision = BahtTextError::ExcessPrecision { decimals: 3 };
+        assert_eq!(
+            excess_precision.to_string(),
+            "Amount has 3 decimal places, more than 2",
+            "Should format ExcessPrecision correctly"
+        );
+
+        // Test BufferTooSmall
+        let buffer_too_small = BahtTextError::BufferTooSmall { required: 36 };
+        assert_eq!(
+            buffer_too_small.to_string(),
+            "Buffer is too small: 36 bytes required",
+            "Should format BufferTooSmall correctly"
+        );
     }
 
     #[test]
@@ -0,0 +1,916 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+#[cfg(feature = "alloc")]
+use crate::decimal::{Amount, Decimal};
+#[cfg(feature = "alloc")]
+use crate::parse::parse_words_styled;
+use crate::write_number;
+#[cfg(feature = "alloc")]
+use crate::{f64_to_decimal, BahtTextError, Strictness, Verification};
+
+/// The word written after บาท when an amount has no satang.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WholeSuffix {
+    /// ถ้วน, the usual suffix on cheques and invoices.
+    #[default]
+    Thuan,
+    /// ตรง, used by some banks and older government forms.
+    Trong,
+    /// Nothing after บาท.
+    Omit,
+}
+
+impl WholeSuffix {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Thuan => "ถ้วน",
+            Self::Trong => "ตรง",
+            Self::Omit => "",
+        }
+    }
+}
+
+/// How an amount is rounded to whole satang.
+///
+/// Rounding is applied to the amount exactly as written, so `1.005` is a true
+/// half and never `1.00499…`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// To the nearest satang, halves away from zero, as [`words`](crate::words) does.
+    #[default]
+    HalfUp,
+    /// To the nearest satang, halves to an even satang (banker's rounding).
+    HalfEven,
+    /// Toward negative infinity.
+    Down,
+    /// Toward positive infinity.
+    Up,
+    /// Toward zero, dropping any further digits.
+    TowardZero,
+    /// To the nearest 25 satang, halves away from zero.
+    NearestQuarter,
+}
+
+impl Rounding {
+    /// Rounds an amount to whole satang from its first two decimals as
+    /// `satang`, the third decimal as `next`, and whether any later decimal
+    /// is non-zero. A result of 100 carries into the baht.
+    pub(crate) fn round_satang(self, negative: bool, satang: u8, next: u8, rest: bool) -> u8 {
+        let inexact = next > 0 || rest;
+        match self {
+            Self::HalfUp => satang + u8::from(next >= 5),
+            Self::HalfEven => {
+                let tie_up = rest || satang % 2 == 1;
+                satang + u8::from(next > 5 || (next == 5 && tie_up))
+            }
+            Self::Down => satang + u8::from(negative && inexact),
+            Self::Up => satang + u8::from(!negative && inexact),
+            Self::TowardZero => satang,
+            Self::NearestQuarter => {
+                let thousandths = u16::from(satang) * 10 + u16::from(next);
+                ((thousandths + 125) / 250 * 25) as u8
+            }
+        }
+    }
+}
+
+/// An amount rounded to the nearest 25 satang, the smallest coin in practical
+/// use, together with its text.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CashAmount {
+    /// The rounded amount in satang
+    pub satang: i128,
+    /// The rounded amount in Thai words
+    pub words: String,
+}
+
+/// Options for how an amount is written in Thai.
+///
+/// The defaults give the same text as [`words`](crate::words) and
+/// [`words_from`](crate::words_from); each builder method changes one
+/// convention, so forms that want a different style do not have to
+/// post-process the returned strings.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use bahttext::BahtText;
+///
+/// let style = BahtText::new().negative_prefix("ติดลบ").leading_one(false);
+/// assert_eq!(style.words_from("-1,500").unwrap(), "ติดลบพันห้าร้อยบาทถ้วน");
+/// assert_eq!(BahtText::new().words_from("-1,500").unwrap(), "ลบหนึ่งพันห้าร้อยบาทถ้วน");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BahtText<'a> {
+    pub(crate) whole_suffix: WholeSuffix,
+    pub(crate) negative_prefix: &'a str,
+    pub(crate) leading_one: bool,
+    pub(crate) zero_baht: bool,
+    significant_digits: Option<usize>,
+    rounding: Rounding,
+    strict: bool,
+    max_baht: Option<u128>,
+}
+
+impl Default for BahtText<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> BahtText<'a> {
+    /// Returns the default options: ถ้วน after whole amounts, ลบ before
+    /// negative ones, หนึ่ง before a leading ร้อย or พัน, ศูนย์บาท before
+    /// satang-only amounts, rounding halves away from zero, and no limit.
+    pub const fn new() -> Self {
+        Self {
+            whole_suffix: WholeSuffix::Thuan,
+            negative_prefix: "ลบ",
+            leading_one: true,
+            zero_baht: true,
+            significant_digits: None,
+            rounding: Rounding::HalfUp,
+            strict: false,
+            max_baht: None,
+        }
+    }
+
+    /// Returns options modelled on the `BAHTTEXT` function of spreadsheets.
+    ///
+    /// Amounts are rounded to 15 significant digits, the precision a
+    /// spreadsheet holds numbers to, before being rounded to the satang, and
+    /// satang-only amounts are written without ศูนย์บาท.
+    ///
+    /// This is not a compatibility mode. Its text has not been compared with
+    /// Excel or LibreOffice Calc, which do not agree with each other on every
+    /// amount either: satang-only amounts, negatives, rounding of the third
+    /// decimal and very large numbers may all come out differently.
+    /// `tests/spreadsheet_corpus.tsv` lists the amounts where this differs
+    /// from [`words`](crate::words), and the spreadsheet version, if any, each
+    /// row has been checked against.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use bahttext::{words, BahtText};
+    ///
+    /// let style = BahtText::spreadsheet();
+    /// assert_eq!(style.try_words(0.75).unwrap(), "เจ็ดสิบห้าสตางค์");
+    /// assert_eq!(words(0.75), "ศูนย์บาทเจ็ดสิบห้าสตางค์");
+    ///
+    /// assert_eq!(style.try_words(0.1249999999999999).unwrap(), "สิบสามสตางค์");
+    /// assert_eq!(words(0.1249999999999999), "ศูนย์บาทสิบสองสตางค์");
+    /// # }
+    /// ```
+    pub const fn spreadsheet() -> Self {
+        let mut options = Self::new().zero_baht(false);
+        options.significant_digits = Some(15);
+        options
+    }
+
+    /// Sets the word written after บาท when there are no satang.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use bahttext::{BahtText, WholeSuffix};
+    ///
+    /// let style = BahtText::new().whole_suffix(WholeSuffix::Trong);
+    /// assert_eq!(style.words_from("100").unwrap(), "หนึ่งร้อยบาทตรง");
+    /// assert_eq!(style.words_from("100.5").unwrap(), "หนึ่งร้อยบาทห้าสิบสตางค์");
+    /// # }
+    /// ```
+    pub const fn whole_suffix(mut self, suffix: WholeSuffix) -> Self {
+        self.whole_suffix = suffix;
+        self
+    }
+
+    /// Sets the word written before negative amounts.
+    ///
+    /// The prefix is borrowed, so one read at runtime can be used without
+    /// leaking it for as long as these options live.
+    pub const fn negative_prefix(mut self, prefix: &'a str) -> Self {
+        self.negative_prefix = prefix;
+        self
+    }
+
+    /// Sets whether หนึ่ง is written before the leading place of the baht,
+    /// as in หนึ่งร้อยบาท, or dropped, as in ร้อยบาท.
+    ///
+    /// Only the very start of the amount is affected: 1,100 reads as
+    /// พันหนึ่งร้อย, never พันร้อย.
+    pub const fn leading_one(mut self, leading_one: bool) -> Self {
+        self.leading_one = leading_one;
+        self
+    }
+
+    /// Sets whether ศูนย์บาท is written before amounts of satang only.
+    ///
+    /// Receipts often write 0.50 as just ห้าสิบสตางค์. An
+    /// exact zero is always ศูนย์บาทถ้วน.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use bahttext::BahtText;
+    ///
+    /// let style = BahtText::new().zero_baht(false);
+    /// assert_eq!(style.words_from("0.50").unwrap(), "ห้าสิบสตางค์");
+    /// assert_eq!(style.words_from("-0.01").unwrap(), "ลบหนึ่งสตางค์");
+    /// assert_eq!(style.words_from("0").unwrap(), "ศูนย์บาทถ้วน");
+    /// # }
+    /// ```
+    pub const fn zero_baht(mut self, zero_baht: bool) -> Self {
+        self.zero_baht = zero_baht;
+        self
+    }
+
+    /// Sets how amounts with more than two decimals are rounded to whole satang.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use bahttext::{BahtText, Rounding};
+    ///
+    /// let tax = BahtText::new().rounding(Rounding::HalfEven);
+    /// assert_eq!(tax.words_from("12.345").unwrap(), "สิบสองบาทสามสิบสี่สตางค์");
+    ///
+    /// let truncate = BahtText::new().rounding(Rounding::TowardZero);
+    /// assert_eq!(truncate.words_from("-0.999").unwrap(), "ลบศูนย์บาทเก้าสิบเก้าสตางค์");
+    /// # }
+    /// ```
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets whether amounts with more than two decimals are rejected rather
+    /// than rounded.
+    ///
+    /// Trailing zeros do not count, so `"12.300"` is accepted. An `f64` is
+    /// judged by its shortest decimal representation. With the significant
+    /// digits of [`spreadsheet`](Self::spreadsheet), decimals are counted
+    /// after rounding to those digits, for text and `f64` input alike, so
+    /// `"0.30000000000000004"` is accepted as the 0.3 a spreadsheet holds.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use bahttext::{BahtText, BahtTextError};
+    ///
+    /// let audit = BahtText::new().strict(true);
+    /// assert_eq!(
+    ///     audit.words_from("12.345").unwrap_err(),
+    ///     BahtTextError::ExcessPrecision { decimals: 3 }
+    /// );
+    /// assert_eq!(audit.try_words(12.35).unwrap(), "สิบสองบาทสามสิบห้าสตางค์");
+    /// # }
+    /// ```
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Rejects amounts more than `max_baht` either side of zero.
+    ///
+    /// Amounts far above the limit, such as a `BigDecimal` of `1e999999999`,
+    /// are rejected without writing out their digits.
+    pub const fn max_baht(mut self, max_baht: u128) -> Self {
+        self.max_baht = Some(max_baht);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BahtText<'_> {
+    /// Converts a monetary amount to Thai text representation.
+    ///
+    /// # Errors
+    /// Returns `BahtTextError::InvalidNumber` if the amount is NaN or infinite,
+    /// `BahtTextError::ExcessPrecision` if it has more than two decimals in
+    /// strict mode, or `BahtTextError::AmountTooLarge` if it is above the limit
+    pub fn try_words(&self, money: f64) -> Result<String, BahtTextError> {
+        self.finish(self.round(self.f64_to_decimal(money)?)?)
+    }
+
+    /// Converts a string representing a monetary amount to Thai text representation.
+    ///
+    /// # Errors
+    /// Returns `BahtTextError` if the input cannot be parsed as a valid number,
+    /// `BahtTextError::ExcessPrecision` if it has more than two decimals in
+    /// strict mode, or `BahtTextError::AmountTooLarge` if it is above the limit
+    pub fn words_from(&self, input: &str) -> Result<String, BahtTextError> {
+        self.finish(self.round(Decimal::parse(input)?)?)
+    }
+
+    /// Converts an exact amount of whole baht and satang to Thai text representation.
+    ///
+    /// # Errors
+    /// Returns `BahtTextError::InvalidNumber` if `satang` is greater than 99,
+    /// or `BahtTextError::AmountTooLarge` if the amount is above the limit
+    pub fn words_exact(
+        &self,
+        negative: bool,
+        baht: u128,
+        satang: u8,
+    ) -> Result<String, BahtTextError> {
+        if satang > 99 {
+            return Err(BahtTextError::InvalidNumber);
+        }
+
+        self.finish(Amount::new(negative, baht, satang))
+    }
+
+    /// Converts an amount in satang to Thai text representation.
+    ///
+    /// # Errors
+    /// Returns `BahtTextError::AmountTooLarge` if the amount is above the limit
+    pub fn words_from_satang(&self, satang: i128) -> Result<String, BahtTextError> {
+        self.finish(satang_to_amount(satang < 0, satang.unsigned_abs()))
+    }
+
+    /// Converts a [`rust_decimal::Decimal`] to Thai text representation.
+    ///
+    /// The amount is read exactly as the `Decimal` holds it, without going
+    /// through `f64`, and rounded with the configured rounding mode.
+    ///
+    /// # Errors
+    /// Returns `BahtTextError::ExcessPrecision` if the amount has more than two
+    /// decimals in strict mode, or `BahtTextError::AmountTooLarge` if it is
+    /// above the limit
+    ///
+    /// # Examples
+    /// ```
+    /// use bahttext::{BahtText, Rounding};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let amount = Decimal::new(1_234_565, 3);
+    /// assert_eq!(
+    ///     BahtText::new().rounding(Rounding::HalfEven).words_from_decimal(amount).unwrap(),
+    ///     "หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์"
+    /// );
+    /// ```
+    #[cfg(feature = "rust_decimal")]
+    pub fn words_from_decimal(
+        &self,
+        amount: rust_decimal::Decimal,
+    ) -> Result<String, BahtTextError> {
+        let digits = format!("{}", amount.mantissa().unsigned_abs());
+        let decimal =
+            Decimal::from_digits(amount.is_sign_negative(), &digits, amount.scale().into());
+        self.finish(self.round(decimal)?)
+    }
+
+    /// Converts a [`bigdecimal::BigDecimal`] to Thai text representation.
+    ///
+    /// The amount is read exactly, however many digits it has, and rounded
+    /// with the configured rounding mode. A large exponent is written out in
+    /// full, like the same amount as a `BigInt`, so the text grows with it;
+    /// set [`max_baht`](Self::max_baht) to refuse such amounts up front.
+    ///
+    /// # Errors
+    /// Returns `BahtTextError::ExcessPrecision` if the amount has more than two
+    /// decimals in strict mode, or `BahtTextError::AmountTooLarge` if it is
+    /// above the limit
+    ///
+    /// # Examples
+    /// ```
+    /// use bahttext::BahtText;
+    /// use bigdecimal::BigDecimal;
+    ///
+    /// let amount: BigDecimal = "1e24".parse().unwrap();
+    /// assert_eq!(
+    ///     BahtText::new().leading_one(false).words_from_big_decimal(&amount).unwrap(),
+    ///     "ล้านล้านล้านล้านบาทถ้วน"
+    /// );
+    /// ```
+    #[cfg(feature = "bigdecimal")]
+    pub fn words_from_big_decimal(
+        &self,
+        amount: &bigdecimal::BigDecimal,
+    ) -> Result<String, BahtTextError> {
+        let (digits, scale) = amount.as_bigint_and_exponent();
+        let negative = digits.sign() == bigdecimal::num_bigint::Sign::Minus;
+        let digits = format!("{}", digits.magnitude());
+        self.finish(self.round(Decimal::from_digits(negative, &digits, scale))?)
+    }
+
+    /// Converts a [`num_bigint::BigInt`] number of whole baht to Thai text
+    /// representation.
+    ///
+    /// # Errors
+    /// Returns `BahtTextError::AmountTooLarge` if the amount is above the limit
+    ///
+    /// # Examples
+    /// ```
+    /// use bahttext::BahtText;
+    /// use num_bigint::BigInt;
+    ///
+    /// let baht = BigInt::from(10).pow(42);
+    /// assert_eq!(
+    ///     BahtText::new().words_from_big_int(&baht).unwrap(),
+    ///     "หนึ่งล้านล้านล้านล้านล้านล้านล้านบาทถ้วน"
+    /// );
+    /// ```
+    #[cfg(feature = "num-bigint")]
+    pub fn words_from_big_int(&self, baht: &num_bigint::BigInt) -> Result<String, BahtTextError> {
+        let negative = baht.sign() == num_bigint::Sign::Minus;
+        let digits = format!("{}", baht.magnitude());
+        self.finish(self.round(Decimal::from_digits(negative, &digits, 0))?)
+    }
+
+    /// Rounds a monetary amount to the nearest 25 satang for payment in cash,
+    /// returning the rounded amount with its text.
+    ///
+    /// The other options still apply, except that halves always round away
+    /// from zero to the nearest 25 satang.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`try_words`](Self::try_words), or
+    /// `BahtTextError::AmountTooLarge` if the rounded amount does not fit in an
+    /// `i128` number of satang
+    ///
+    /// # Examples
+    /// ```
+    /// use bahttext::BahtText;
+    ///
+    /// let cash = BahtText::new().cash_words(99.88).unwrap();
+    /// assert_eq!(cash.satang, 10_000);
+    /// assert_eq!(cash.words, "หนึ่งร้อยบาทถ้วน");
+    /// ```
+    pub fn cash_words(&self, money: f64) -> Result<CashAmount, BahtTextError> {
+        self.cash(self.f64_to_decimal(money)?)
+    }
+
+    /// Rounds a string representing a monetary amount to the nearest 25 satang
+    /// for payment in cash, returning the rounded amount with its text.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`words_from`](Self::words_from), or
+    /// `BahtTextError::AmountTooLarge` if the rounded amount does not fit in an
+    /// `i128` number of satang
+    pub fn cash_words_from(&self, input: &str) -> Result<CashAmount, BahtTextError> {
+        self.cash(Decimal::parse(input)?)
+    }
+
+    /// Parses Thai amount text written with these options back into satang.
+    ///
+    /// This is [`parse_words_with`](crate::parse_words_with), except that
+    /// negative amounts start with the [`negative_prefix`](Self::negative_prefix)
+    /// set here, whole baht end with the [`whole_suffix`](Self::whole_suffix),
+    /// หนึ่ง is left out before the leading place if
+    /// [`leading_one`](Self::leading_one) is off, and satang may stand alone
+    /// without ศูนย์บาท if [`zero_baht`](Self::zero_baht) is off.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`parse_words`](crate::parse_words)
+    ///
+    /// # Examples
+    /// ```
+    /// use bahttext::{parse_words, BahtText, Strictness, WholeSuffix};
+    ///
+    /// let style = BahtText::new().whole_suffix(WholeSuffix::Trong);
+    /// assert_eq!(style.parse_words("ห้าร้อยบาทตรง", Strictness::Strict).unwrap(), 50_000);
+    /// assert!(style.parse_words("ห้าร้อยบาท", Strictness::Strict).is_err());
+    /// assert!(parse_words("ห้าร้อยบาทตรง").is_err());
+    /// ```
+    pub fn parse_words(&self, text: &str, strictness: Strictness) -> Result<i128, BahtTextError> {
+        parse_words_styled(text, strictness, self)
+    }
+
+    /// Checks that Thai amount text written with these options says the same
+    /// as `money`.
+    ///
+    /// This is [`verify_words`](crate::verify_words), except that the text is
+    /// read with [`parse_words`](Self::parse_words) and `money` is rounded the
+    /// way [`try_words`](Self::try_words) rounds it.
+    ///
+    /// # Errors
+    /// Returns the errors of [`try_words`](Self::try_words) for `money`, other
+    /// than for the limit, and those of [`parse_words`](Self::parse_words)
+    /// for the text, or `BahtTextError::AmountTooLarge` if `money` does not
+    /// fit in an `i128` number of satang
+    ///
+    /// # Examples
+    /// ```
+    /// use bahttext::{verify_words, BahtText, Strictness, Verification, WholeSuffix};
+    ///
+    /// let text = "หนึ่งพันบาทตรง";
+    /// assert!(verify_words(1_000.0, text, Strictness::Lenient).is_err());
+    ///
+    /// let style = BahtText::new().whole_suffix(WholeSuffix::Trong);
+    /// assert_eq!(
+    ///     style.verify_words(1_000.0, text, Strictness::Strict).unwrap(),
+    ///     Verification::Match
+    /// );
+    /// ```
+    pub fn verify_words(
+        &self,
+        money: f64,
+        text: &str,
+        strictness: Strictness,
+    ) -> Result<Verification, BahtTextError> {
+        let expected = self
+            .round(self.f64_to_decimal(money)?)?
+            .to_satang()
+            .ok_or(BahtTextError::AmountTooLarge)?;
+        let found = self.parse_words(text, strictness)?;
+        Ok(Verification::of(expected, found))
+    }
+
+    fn cash(&self, decimal: Decimal) -> Result<CashAmount, BahtTextError> {
+        let options = self.rounding(Rounding::NearestQuarter);
+        let amount = options.round(decimal)?;
+        let satang = amount.to_satang().ok_or(BahtTextError::AmountTooLarge)?;
+        Ok(CashAmount {
+            satang,
+            words: options.finish(amount)?,
+        })
+    }
+
+    fn f64_to_decimal(&self, money: f64) -> Result<Decimal, BahtTextError> {
+        match self.significant_digits {
+            Some(digits) if money.is_finite() => {
+                Decimal::parse(&format!("{:.*e}", digits - 1, money))
+            }
+            _ => f64_to_decimal(money),
+        }
+    }
+
+    fn round(&self, decimal: Decimal) -> Result<Amount, BahtTextError> {
+        let decimal = match self.significant_digits {
+            Some(digits) => decimal.round_significant(digits),
+            None => decimal,
+        };
+        if self.strict && decimal.decimals() > 2 {
+            return Err(BahtTextError::ExcessPrecision {
+                decimals: decimal.decimals(),
+            });
+        }
+        // Any limit is below 10^39, so a longer amount need not be written out
+        if self.max_baht.is_some() && decimal.integer_digits() > 39 {
+            return Err(BahtTextError::AmountTooLarge);
+        }
+
+        decimal.round_with(self.rounding)
+    }
+
+    fn finish(&self, amount: Amount) -> Result<String, BahtTextError> {
+        match self.max_baht {
+            Some(max_baht) if amount.exceeds(max_baht) => Err(BahtTextError::AmountTooLarge),
+            _ => Ok(self.amount_words(&amount)),
+        }
+    }
+
+    /// Writes an amount without checking the limit.
+    pub(crate) fn amount_words(&self, amount: &Amount) -> String {
+        let mut text = String::new();
+        self.write_parts(&mut text, amount.negative, &amount.baht, amount.satang)
+            .expect("a String can always be written to");
+        text
+    }
+}
+
+impl BahtText<'_> {
+    /// Writes an amount given as its sign, whole baht digits and satang.
+    pub(crate) fn write_parts<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        negative: bool,
+        baht: &str,
+        satang: u8,
+    ) -> fmt::Result {
+        if negative {
+            w.write_str(self.negative_prefix)?;
+        }
+
+        let zero_baht = baht.bytes().all(|b| b == b'0');
+        if self.zero_baht || !zero_baht || satang == 0 {
+            write_number(w, baht, self.leading_one)?;
+            w.write_str("บาท")?;
+        }
+
+        if satang == 0 {
+            w.write_str(self.whole_suffix.as_str())
+        } else {
+            let digits = [b'0' + satang / 10, b'0' + satang % 10];
+            write_number(w, core::str::from_utf8(&digits).unwrap(), true)?;
+            w.write_str("สตางค์")
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn satang_to_amount(negative: bool, satang: u128) -> Amount {
+    Amount::new(negative, satang / 100, (satang % 100) as u8)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_match_words() {
+        for input in ["0", "-0.5", "100", "1,234.56", "-21.01", "1e24"] {
+            assert_eq!(
+                BahtText::new().words_from(input),
+                crate::words_from(input),
+                "Fail case: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_satang() {
+        use Rounding::*;
+
+        let cases = [
+            (HalfUp, false, 99, 5, false, 100),
+            (HalfUp, true, 12, 4, true, 12),
+            (HalfEven, false, 12, 5, false, 12),
+            (HalfEven, false, 12, 5, true, 13),
+            (HalfEven, false, 13, 5, false, 14),
+            (Down, true, 12, 0, true, 13),
+            (Down, false, 12, 9, false, 12),
+            (Up, false, 12, 0, true, 13),
+            (Up, true, 12, 9, false, 12),
+            (TowardZero, false, 12, 9, true, 12),
+            (NearestQuarter, false, 12, 5, false, 25),
+            (NearestQuarter, false, 87, 4, true, 75),
+            (NearestQuarter, false, 87, 5, false, 100),
+        ];
+
+        for &(rounding, negative, satang, next, rest, expected) in &cases {
+            assert_eq!(
+                rounding.round_satang(negative, satang, next, rest),
+                expected,
+                "Fail case: {:?} {} {} {} {}",
+                rounding,
+                negative,
+                satang,
+                next,
+                rest
+            );
+        }
+    }
+
+    #[test]
+    fn test_leading_one() {
+        let style = BahtText::new().leading_one(false);
+        let cases = [
+            ("1", "หนึ่งบาทถ้วน"),
+            ("11", "สิบเอ็ดบาทถ้วน"),
+            ("100", "ร้อยบาทถ้วน"),
+            ("1,100", "พันหนึ่งร้อยบาทถ้วน"),
+            ("1,000,000", "ล้านบาทถ้วน"),
+            ("10,000,000", "สิบล้านบาทถ้วน"),
+            ("-100.01", "ลบร้อยบาทหนึ่งสตางค์"),
+            ("201", "สองร้อยเอ็ดบาทถ้วน"),
+        ];
+
+        for &(input, expected) in &cases {
+            assert_eq!(
+                style.words_from(input).unwrap(),
+                expected,
+                "Fail case: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_words_round_trip() {
+        let amounts = [
+            0,
+            1,
+            -50,
+            100,
+            -10_001,
+            110_000,
+            100_000_000,
+            -110_000_025,
+            100_000_000_000_000,
+            i128::MAX,
+            i128::MIN,
+        ];
+
+        for whole_suffix in [WholeSuffix::Thuan, WholeSuffix::Trong, WholeSuffix::Omit] {
+            for negative_prefix in ["ลบ", "ติดลบ", "ลบ "] {
+                for leading_one in [true, false] {
+                    for zero_baht in [true, false] {
+                        let style = BahtText::new()
+                            .whole_suffix(whole_suffix)
+                            .negative_prefix(negative_prefix)
+                            .leading_one(leading_one)
+                            .zero_baht(zero_baht)
+                            .rounding(Rounding::HalfEven)
+                            .strict(true)
+                            .max_baht(u128::MAX);
+
+                        for satang in amounts {
+                            let text = style.words_from_satang(satang).unwrap();
+                            for strictness in [Strictness::Strict, Strictness::Lenient] {
+                                assert_eq!(
+                                    style.parse_words(&text, strictness),
+                                    Ok(satang),
+                                    "Fail case: {} {:?}",
+                                    text,
+                                    style
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_whole_suffix() {
+        let cases = [
+            (WholeSuffix::Thuan, "ลบสิบเอ็ดบาทถ้วน"),
+            (WholeSuffix::Trong, "ลบสิบเอ็ดบาทตรง"),
+            (WholeSuffix::Omit, "ลบสิบเอ็ดบาท"),
+        ];
+
+        for &(suffix, expected) in &cases {
+            let style = BahtText::new().whole_suffix(suffix);
+            assert_eq!(style.words_from("-11").unwrap(), expected);
+            assert_eq!(style.words_from("-11.01").unwrap(), "ลบสิบเอ็ดบาทหนึ่งสตางค์");
+            assert_eq!(style.parse_words(expected, Strictness::Strict), Ok(-1_100));
+        }
+    }
+
+    #[test]
+    fn test_verify_words() {
+        let cases = [
+            (WholeSuffix::Trong, "หนึ่งพันบาทตรง"),
+            (WholeSuffix::Omit, "หนึ่งพันบาท"),
+        ];
+
+        for &(suffix, text) in &cases {
+            let style = BahtText::new().whole_suffix(suffix);
+            for strictness in [Strictness::Strict, Strictness::Lenient] {
+                assert_eq!(
+                    style.verify_words(1_000.0, text, strictness),
+                    Ok(Verification::Match),
+                    "Fail case: {}",
+                    text
+                );
+                assert_eq!(
+                    style.verify_words(1_000.5, text, strictness),
+                    Ok(Verification::Mismatch { found: 100_000 }),
+                    "Fail case: {}",
+                    text
+                );
+            }
+        }
+
+        let style = BahtText::new().leading_one(false).rounding(Rounding::Down);
+        assert_eq!(
+            style.verify_words(
+                999.999,
+                "เก้าร้อยเก้าสิบเก้าบาทเก้าสิบเก้าสตางค์",
+                Strictness::Strict
+            ),
+            Ok(Verification::Match)
+        );
+        assert_eq!(
+            style.verify_words(1_000.0, "พันบาทถ้วน", Strictness::Strict),
+            Ok(Verification::Match)
+        );
+        assert_eq!(
+            style.verify_words(f64::NAN, "พันบาทถ้วน", Strictness::Strict),
+            Err(BahtTextError::InvalidNumber)
+        );
+        assert_eq!(
+            BahtText::new()
+                .strict(true)
+                .verify_words(1.005, "หนึ่งบาทถ้วน", Strictness::Strict),
+            Err(BahtTextError::ExcessPrecision { decimals: 3 })
+        );
+    }
+
+    #[test]
+    fn test_zero_baht() {
+        let style = BahtText::new().zero_baht(false);
+        let cases = [
+            (0, "ศูนย์บาทถ้วน"),
+            (1, "หนึ่งสตางค์"),
+            (-99, "ลบเก้าสิบเก้าสตางค์"),
+            (100, "หนึ่งบาทถ้วน"),
+            (101, "หนึ่งบาทหนึ่งสตางค์"),
+        ];
+
+        for &(satang, expected) in &cases {
+            let text = style.words_from_satang(satang).unwrap();
+            assert_eq!(text, expected);
+            assert_eq!(style.parse_words(&text, Strictness::Strict), Ok(satang));
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        let audit = BahtText::new().strict(true);
+        let excess = |decimals| Err(BahtTextError::ExcessPrecision { decimals });
+
+        assert_eq!(audit.words_from("12.345"), excess(3));
+        assert_eq!(audit.words_from("-0.0001"), excess(4));
+        assert_eq!(audit.words_from("1e-300"), excess(300));
+        assert_eq!(audit.try_words(0.1 + 0.2), excess(17));
+        assert_eq!(audit.words_from("12.3400").unwrap(), "สิบสองบาทสามสิบสี่สตางค์");
+        assert_eq!(
+            audit.words_from("1.2345e2").unwrap(),
+            "หนึ่งร้อยยี่สิบสามบาทสี่สิบห้าสตางค์"
+        );
+        assert_eq!(audit.try_words(1_234.5).unwrap(), crate::words(1_234.5));
+        assert_eq!(
+            BahtText::spreadsheet()
+                .strict(true)
+                .try_words(0.1 + 0.2)
+                .unwrap(),
+            "สามสิบสตางค์"
+        );
+
+        let spreadsheet = BahtText::spreadsheet().strict(true);
+        for input in ["0.30000000000000004", "3.0000000000000004e-1"] {
+            assert_eq!(
+                spreadsheet.words_from(input).unwrap(),
+                "สามสิบสตางค์",
+                "Fail case: {}",
+                input
+            );
+        }
+        assert_eq!(spreadsheet.words_from("0.305"), excess(3));
+        assert_eq!(spreadsheet.try_words(0.305), excess(3));
+    }
+
+    #[test]
+    fn test_cash_words() {
+        let cases = [
+            ("0", 0, "ศูนย์บาทถ้วน"),
+            ("0.12", 0, "ศูนย์บาทถ้วน"),
+            ("0.125", 25, "ศูนย์บาทยี่สิบห้าสตางค์"),
+            ("10.37", 1_025, "สิบบาทยี่สิบห้าสตางค์"),
+            ("10.38", 1_050, "สิบบาทห้าสิบสตางค์"),
+            ("-10.63", -1_075, "ลบสิบบาทเจ็ดสิบห้าสตางค์"),
+            ("99.875", 10_000, "หนึ่งร้อยบาทถ้วน"),
+        ];
+
+        for &(input, satang, words) in &cases {
+            let cash = BahtText::new().cash_words_from(input).unwrap();
+            assert_eq!(
+                cash,
+                CashAmount {
+                    satang,
+                    words: words.to_string()
+                },
+                "Fail case: {}",
+                input
+            );
+        }
+
+        let style = BahtText::new()
+            .rounding(Rounding::TowardZero)
+            .zero_baht(false);
+        assert_eq!(style.cash_words(0.85).unwrap().words, "เจ็ดสิบห้าสตางค์");
+        assert_eq!(style.cash_words(1e40), Err(BahtTextError::AmountTooLarge));
+        assert_eq!(
+            style.cash_words(f64::NAN),
+            Err(BahtTextError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn test_custom_words() {
+        let style = BahtText::new()
+            .whole_suffix(WholeSuffix::Omit)
+            .negative_prefix("ติดลบ")
+            .max_baht(1_000);
+
+        assert_eq!(style.words_exact(true, 5, 0).unwrap(), "ติดลบห้าบาท");
+        assert_eq!(
+            style.words_from_satang(-150).unwrap(),
+            "ติดลบหนึ่งบาทห้าสิบสตางค์"
+        );
+        assert_eq!(
+            style.try_words(1_000.01),
+            Err(BahtTextError::AmountTooLarge)
+        );
+        assert_eq!(
+            style.words_exact(false, 0, 100),
+            Err(BahtTextError::InvalidNumber)
+        );
+
+        // A prefix read at runtime is borrowed, not leaked
+        let prefix = String::from("ติด") + "ลบ";
+        let style = BahtText::new().negative_prefix(&prefix);
+        assert_eq!(style.words_from("-5").unwrap(), "ติดลบห้าบาทถ้วน");
+    }
+}
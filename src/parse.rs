@@ -0,0 +1,724 @@
+use alloc::vec::Vec;
+
+use crate::decimal::signed;
+use crate::{BahtText, BahtTextError, WholeSuffix};
+
+/// How closely amount text must follow the spelling [`words`](crate::words) uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strictness {
+    /// Only the spelling `words` produces, ignoring whitespace.
+    #[default]
+    Strict,
+    /// Also equivalent spellings: หนึ่ง left out before ร้อย, พัน, หมื่น, แสน
+    /// and ล้าน or written before สิบ, สองสิบ for ยี่สิบ, and หนึ่ง for เอ็ด.
+    ///
+    /// ตรง or nothing after whole baht is a style rather than a spelling, so
+    /// it is read by [`BahtText::parse_words`] and [`BahtText::verify_words`]
+    /// with the [`whole_suffix`](BahtText::whole_suffix) it was written with.
+    Lenient,
+}
+
+/// The outcome of checking amount text against a numeric amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verification {
+    /// The text reads as the amount.
+    Match,
+    /// The text reads as a different amount.
+    Mismatch {
+        /// The amount the text actually says, in satang
+        found: i128,
+    },
+}
+
+/// A word of Thai amount text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Word {
+    Zero,
+    Digit(u8),
+    /// เอ็ด, the units digit one after a larger digit
+    Ed,
+    /// ยี่, the digit two before สิบ
+    Yi,
+    /// A power of ten from สิบ (1) to ล้าน (6)
+    Place(u32),
+    Baht,
+    Satang,
+    Thuan,
+    Trong,
+    Minus,
+}
+
+const VOCABULARY: [(&str, Word); 22] = [
+    ("ศูนย์", Word::Zero),
+    ("หนึ่ง", Word::Digit(1)),
+    ("สอง", Word::Digit(2)),
+    ("สาม", Word::Digit(3)),
+    ("สี่", Word::Digit(4)),
+    ("ห้า", Word::Digit(5)),
+    ("หก", Word::Digit(6)),
+    ("เจ็ด", Word::Digit(7)),
+    ("แปด", Word::Digit(8)),
+    ("เก้า", Word::Digit(9)),
+    ("เอ็ด", Word::Ed),
+    ("ยี่", Word::Yi),
+    ("สิบ", Word::Place(1)),
+    ("ร้อย", Word::Place(2)),
+    ("พัน", Word::Place(3)),
+    ("หมื่น", Word::Place(4)),
+    ("แสน", Word::Place(5)),
+    ("ล้าน", Word::Place(6)),
+    ("บาท", Word::Baht),
+    ("สตางค์", Word::Satang),
+    ("ถ้วน", Word::Thuan),
+    ("ตรง", Word::Trong),
+];
+
+/// Parses Thai amount text, as produced by [`words`](crate::words), back into satang.
+///
+/// Every form `words` emits is understood: ยี่สิบ and เอ็ด, ล้าน repeated for
+/// large amounts, ถ้วน after whole baht, and ลบ for negatives. Whitespace
+/// between words is ignored. Use
+/// [`BahtText::parse_words`](crate::BahtText::parse_words) for text written
+/// with ตรง, no suffix, or satang on their own without ศูนย์บาท.
+///
+/// # Errors
+/// Returns `BahtTextError::InvalidWords` with the byte offset of the first word
+/// that does not fit, or `BahtTextError::AmountTooLarge` if the amount does not
+/// fit in an `i128` number of satang
+///
+/// # Examples
+/// ```
+/// use bahttext::{parse_words, BahtTextError};
+///
+/// let satang = parse_words("หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์").unwrap();
+/// assert_eq!(satang, 123_456);
+///
+/// assert_eq!(parse_words("ลบสิบเอ็ดล้านบาทถ้วน").unwrap(), -1_100_000_000);
+///
+/// assert_eq!(
+///     parse_words("หนึ่งร้อยบาทห้าสิบ").unwrap_err(),
+///     BahtTextError::InvalidWords {
+///         position: 54,
+///         expected: "สตางค์",
+///     }
+/// );
+/// ```
+pub fn parse_words(text: &str) -> Result<i128, BahtTextError> {
+    parse_words_with(text, Strictness::Strict)
+}
+
+/// Parses Thai amount text back into satang, accepting the spellings allowed by `strictness`.
+///
+/// # Errors
+/// Returns the same errors as [`parse_words`]
+///
+/// # Examples
+/// ```
+/// use bahttext::{parse_words_with, Strictness};
+///
+/// let text = "ร้อยสิบหนึ่งบาทถ้วน";
+/// assert!(parse_words_with(text, Strictness::Strict).is_err());
+/// assert_eq!(parse_words_with(text, Strictness::Lenient).unwrap(), 11_100);
+/// ```
+pub fn parse_words_with(text: &str, strictness: Strictness) -> Result<i128, BahtTextError> {
+    parse_words_styled(text, strictness, &BahtText::new())
+}
+
+/// Parses text written in the style of `options`: its negative prefix, its
+/// suffix after whole baht, หนึ่ง left out before the leading place unless
+/// `leading_one`, and satang alone without ศูนย์บาท unless `zero_baht`.
+pub(crate) fn parse_words_styled(
+    text: &str,
+    strictness: Strictness,
+    options: &BahtText<'_>,
+) -> Result<i128, BahtTextError> {
+    let mut parser = Parser::new(text, strictness, options.negative_prefix)?;
+    parser.leading_one = options.leading_one;
+    parser.satang_alone = !options.zero_baht;
+    parser.whole_suffix = match options.whole_suffix {
+        WholeSuffix::Thuan => Some(Word::Thuan),
+        WholeSuffix::Trong => Some(Word::Trong),
+        WholeSuffix::Omit => None,
+    };
+    parser.amount()
+}
+
+/// Checks that Thai amount text on a document says the same as `money`.
+///
+/// The text is read with [`parse_words_with`] and compared with `money` rounded
+/// to the nearest satang, the way [`words`](crate::words) rounds it. Use
+/// [`BahtText::verify_words`] for text written in another style, such as with
+/// ตรง after whole baht.
+///
+/// # Errors
+/// Returns `BahtTextError::InvalidNumber` if `money` is NaN or infinite, the
+/// errors of [`parse_words`] if the text cannot be read, or
+/// `BahtTextError::AmountTooLarge` if `money` does not fit in an `i128` number of satang
+///
+/// # Examples
+/// ```
+/// use bahttext::{verify_words, Strictness, Verification};
+///
+/// let text = "หนึ่งพันห้าร้อยบาทถ้วน";
+/// assert_eq!(verify_words(1_500.0, text, Strictness::Strict).unwrap(), Verification::Match);
+/// assert_eq!(
+///     verify_words(1_050.0, text, Strictness::Strict).unwrap(),
+///     Verification::Mismatch { found: 150_000 }
+/// );
+///
+/// let text = "พันห้าร้อยบาทถ้วน";
+/// assert!(verify_words(1_500.0, text, Strictness::Strict).is_err());
+/// assert_eq!(verify_words(1_500.0, text, Strictness::Lenient).unwrap(), Verification::Match);
+/// ```
+pub fn verify_words(
+    money: f64,
+    text: &str,
+    strictness: Strictness,
+) -> Result<Verification, BahtTextError> {
+    let expected = crate::f64_to_amount(money)?
+        .to_satang()
+        .ok_or(BahtTextError::AmountTooLarge)?;
+    let found = parse_words_with(text, strictness)?;
+    Ok(Verification::of(expected, found))
+}
+
+impl Verification {
+    pub(crate) fn of(expected: i128, found: i128) -> Self {
+        if found == expected {
+            Self::Match
+        } else {
+            Self::Mismatch { found }
+        }
+    }
+}
+
+struct Parser {
+    words: Vec<(usize, Word)>,
+    index: usize,
+    end: usize,
+    lenient: bool,
+    /// Whether หนึ่ง is written before the leading ร้อย, พัน, หมื่น, แสน or ล้าน
+    leading_one: bool,
+    /// The word that ends whole baht, or `None` for the end of the text
+    whole_suffix: Option<Word>,
+    /// Whether satang may be written without ศูนย์บาท
+    satang_alone: bool,
+}
+
+impl Parser {
+    fn new(
+        text: &str,
+        strictness: Strictness,
+        negative_prefix: &str,
+    ) -> Result<Self, BahtTextError> {
+        let mut words = Vec::new();
+        let mut rest = text.trim_start();
+
+        let negative_prefix = negative_prefix.trim();
+        if !negative_prefix.is_empty() && rest.starts_with(negative_prefix) {
+            words.push((text.len() - rest.len(), Word::Minus));
+            rest = rest[negative_prefix.len()..].trim_start();
+        }
+
+        while !rest.is_empty() {
+            let position = text.len() - rest.len();
+            let &(spelling, word) = VOCABULARY
+                .iter()
+                .find(|(spelling, _)| rest.starts_with(spelling))
+                .ok_or(BahtTextError::InvalidWords {
+                    position,
+                    expected: "a Thai number word",
+                })?;
+
+            words.push((position, word));
+            rest = rest[spelling.len()..].trim_start();
+        }
+
+        Ok(Self {
+            words,
+            index: 0,
+            end: text.len(),
+            lenient: strictness == Strictness::Lenient,
+            leading_one: true,
+            whole_suffix: Some(Word::Thuan),
+            satang_alone: false,
+        })
+    }
+
+    fn peek(&self) -> Option<Word> {
+        self.words.get(self.index).map(|&(_, word)| word)
+    }
+
+    fn position(&self) -> usize {
+        self.words
+            .get(self.index)
+            .map_or(self.end, |&(position, _)| position)
+    }
+
+    fn eat(&mut self, word: Word) -> bool {
+        let found = self.peek() == Some(word);
+        if found {
+            self.index += 1;
+        }
+        found
+    }
+
+    fn error(&self, expected: &'static str) -> BahtTextError {
+        BahtTextError::InvalidWords {
+            position: self.position(),
+            expected,
+        }
+    }
+
+    fn expect(&mut self, word: Word, expected: &'static str) -> Result<(), BahtTextError> {
+        if self.eat(word) {
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn amount(&mut self) -> Result<i128, BahtTextError> {
+        let negative = self.eat(Word::Minus);
+
+        let start = self.index;
+        let baht = self.number()?;
+        let (baht, satang) = if self.satang_alone && self.peek() == Some(Word::Satang) {
+            // Satang alone, with ศูนย์บาท left out
+            self.index = start;
+            (0, self.satang()?)
+        } else {
+            self.expect(Word::Baht, "บาท")?;
+            let whole = match self.whole_suffix {
+                Some(suffix) => self.eat(suffix),
+                None => self.peek().is_none(),
+            };
+            let number = matches!(
+                self.peek(),
+                Some(Word::Zero | Word::Digit(_) | Word::Ed | Word::Yi | Word::Place(_))
+            );
+            if whole {
+                (baht, 0)
+            } else if number {
+                (baht, self.satang()?)
+            } else {
+                return Err(self.error(match self.whole_suffix {
+                    Some(Word::Trong) => "ตรง or satang",
+                    Some(_) => "ถ้วน or satang",
+                    None => "satang or end of text",
+                }));
+            }
+        };
+
+        if self.peek().is_some() {
+            return Err(self.error("end of text"));
+        }
+
+        baht.checked_mul(100)
+            .and_then(|total| total.checked_add(satang))
+            .and_then(|total| signed(negative, total))
+            .ok_or(BahtTextError::AmountTooLarge)
+    }
+
+    /// Reads satang from หนึ่ง to เก้าสิบเก้า followed by สตางค์.
+    fn satang(&mut self) -> Result<u128, BahtTextError> {
+        let position = self.position();
+        let satang = self.number()?;
+        if satang == 0 || satang > 99 {
+            return Err(BahtTextError::InvalidWords {
+                position,
+                expected: "satang from หนึ่ง to เก้าสิบเก้า",
+            });
+        }
+        self.expect(Word::Satang, "สตางค์")?;
+        Ok(satang)
+    }
+
+    /// Reads a whole number: ศูนย์, or groups of up to six digits joined by ล้าน.
+    fn number(&mut self) -> Result<u128, BahtTextError> {
+        if self.eat(Word::Zero) {
+            return Ok(0);
+        }
+
+        let (position, start) = (self.position(), self.index);
+        let mut value = self.group(false)?;
+        let omits_one = self.lenient || !self.leading_one;
+        if value == 0 {
+            if !(omits_one && self.peek() == Some(Word::Place(6))) {
+                return Err(self.error("a number"));
+            }
+            value = 1;
+        } else if !self.leading_one
+            && !self.lenient
+            && self.index == start + 1
+            && value == 1
+            && self.peek() == Some(Word::Place(6))
+        {
+            return Err(BahtTextError::InvalidWords {
+                position,
+                expected: "ล้าน without หนึ่ง",
+            });
+        }
+
+        while self.eat(Word::Place(6)) {
+            let group = self.group(true)?;
+            value = value
+                .checked_mul(1_000_000)
+                .and_then(|v| v.checked_add(group))
+                .ok_or(BahtTextError::AmountTooLarge)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Reads the digits below ล้าน, each followed by its place word.
+    ///
+    /// `after_number` tells whether larger digits were read already, in which
+    /// case a units digit of one must be written เอ็ด.
+    fn group(&mut self, after_number: bool) -> Result<u128, BahtTextError> {
+        let mut value = 0;
+        let mut smallest_place = 6;
+
+        loop {
+            let position = self.position();
+            let digit = match self.peek() {
+                Some(word @ (Word::Digit(_) | Word::Yi | Word::Ed)) => {
+                    self.index += 1;
+                    Some(word)
+                }
+                _ => None,
+            };
+            let place = match self.peek() {
+                Some(Word::Place(place)) if place < 6 => {
+                    self.index += 1;
+                    Some(place)
+                }
+                _ => None,
+            };
+
+            let invalid = |expected| BahtTextError::InvalidWords { position, expected };
+            let wrote_before = after_number || value > 0;
+
+            let (n, place) = match (digit, place) {
+                (None, None) => break,
+                (_, Some(place)) if place >= smallest_place => {
+                    return Err(invalid("a smaller place value"))
+                }
+                (None, Some(1)) => (1, 1),
+                (Some(Word::Yi), Some(1)) => (2, 1),
+                (Some(Word::Digit(n)), Some(1)) if n > 2 || self.lenient => (n, 1),
+                (_, Some(1)) => return Err(invalid("สิบ, ยี่สิบ or a digit from สาม to เก้า")),
+                (Some(Word::Digit(1)), Some(_))
+                    if !wrote_before && !self.leading_one && !self.lenient =>
+                {
+                    return Err(invalid("ร้อย, พัน, หมื่น or แสน without หนึ่ง"))
+                }
+                (Some(Word::Digit(n)), Some(place)) => (n, place),
+                (None, Some(place)) if self.lenient || (!wrote_before && !self.leading_one) => {
+                    (1, place)
+                }
+                (_, Some(_)) => return Err(invalid("a digit from หนึ่ง to เก้า")),
+                (Some(Word::Yi), None) => return Err(invalid("สิบ after ยี่")),
+                (Some(Word::Ed), None) if wrote_before => (1, 0),
+                (Some(Word::Ed), None) => return Err(invalid("หนึ่ง")),
+                (Some(Word::Digit(1)), None) if wrote_before && !self.lenient => {
+                    return Err(invalid("เอ็ด"))
+                }
+                (Some(Word::Digit(n)), None) => (n, 0),
+                (Some(_), None) => unreachable!("only digits are read before a place"),
+            };
+
+            value += u128::from(n) * 10u128.pow(place);
+            smallest_place = place;
+            if place == 0 {
+                break;
+            }
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{words_from, words_from_satang_i128};
+
+    #[test]
+    fn test_parse_words_round_trip() {
+        let samples = (0..=2_000).chain((0..=100).map(|n| n * 10_101)).chain([
+            100_000_001,
+            100_000_000,
+            100_000_100_000_000,
+            1_234_567_890_123_456_789,
+            i128::MAX,
+        ]);
+        assert_eq!(
+            parse_words(&words_from_satang_i128(i128::MIN)),
+            Ok(i128::MIN)
+        );
+
+        for satang in samples {
+            for satang in [satang, -satang] {
+                let text = words_from_satang_i128(satang);
+                assert_eq!(parse_words(&text), Ok(satang), "Fail case: {}", text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_words_ignores_whitespace() {
+        assert_eq!(parse_words("  หนึ่งพัน สองร้อย บาท  ห้าสิบ สตางค์ "), Ok(120_050));
+    }
+
+    #[test]
+    fn test_parse_words_too_large() {
+        let text = words_from(&format!("{}", i128::MAX / 100 + 1)).unwrap();
+        assert_eq!(parse_words(&text), Err(BahtTextError::AmountTooLarge));
+
+        let text = words_from(&"9".repeat(60)).unwrap();
+        assert_eq!(parse_words(&text), Err(BahtTextError::AmountTooLarge));
+    }
+
+    #[test]
+    fn test_parse_words_lenient() {
+        let cases = [
+            ("ร้อยบาทถ้วน", 10_000),
+            ("พันร้อยบาทถ้วน", 110_000),
+            ("หมื่นบาทถ้วน", 1_000_000),
+            ("แสนบาทถ้วน", 10_000_000),
+            ("ล้านบาทถ้วน", 100_000_000),
+            ("ล้านล้านบาทถ้วน", 100_000_000_000_000),
+            ("หนึ่งล้านร้อยบาทถ้วน", 100_010_000),
+            ("หนึ่งสิบบาทถ้วน", 1_000),
+            ("สองสิบบาทถ้วน", 2_000),
+            ("สิบหนึ่งบาทถ้วน", 1_100),
+            ("ร้อยหนึ่งบาทสิบหนึ่งสตางค์", 10_111),
+            ("หนึ่งร้อยเอ็ดบาทถ้วน", 10_100),
+        ];
+
+        for &(text, satang) in &cases {
+            assert_eq!(
+                parse_words_with(text, Strictness::Lenient),
+                Ok(satang),
+                "Fail case: {}",
+                text
+            );
+            if text != "หนึ่งร้อยเอ็ดบาทถ้วน" {
+                assert!(parse_words(text).is_err(), "Should be strict: {}", text);
+            }
+        }
+
+        // Still rejected when lenient
+        for text in ["ยี่บาทถ้วน", "เอ็ดบาทถ้วน", "หนึ่งร้อยหนึ่งพันบาทถ้วน", "บาทถ้วน"]
+        {
+            assert!(
+                parse_words_with(text, Strictness::Lenient).is_err(),
+                "Should return InvalidWords for {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_words() {
+        let text = "หนึ่งพันบาทห้าสิบสตางค์";
+        assert_eq!(
+            verify_words(1_000.5, text, Strictness::Strict),
+            Ok(Verification::Match)
+        );
+        assert_eq!(
+            verify_words(1_000.504, text, Strictness::Strict),
+            Ok(Verification::Match)
+        );
+        assert_eq!(
+            verify_words(1_000.05, text, Strictness::Strict),
+            Ok(Verification::Mismatch { found: 100_050 })
+        );
+        assert_eq!(
+            verify_words(-1_000.5, text, Strictness::Strict),
+            Ok(Verification::Mismatch { found: 100_050 })
+        );
+
+        assert_eq!(
+            verify_words(f64::NAN, text, Strictness::Strict),
+            Err(BahtTextError::InvalidNumber)
+        );
+        assert_eq!(
+            verify_words(1e40, text, Strictness::Strict),
+            Err(BahtTextError::AmountTooLarge)
+        );
+        assert!(matches!(
+            verify_words(1_000.5, "หนึ่งพันบาท", Strictness::Lenient),
+            Err(BahtTextError::InvalidWords { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_words_styled() {
+        let cases = [
+            (WholeSuffix::Thuan, "สองพันบาทถ้วน", Some(200_000)),
+            (WholeSuffix::Thuan, "สองพันบาทตรง", None),
+            (WholeSuffix::Trong, "สองพันบาทตรง", Some(200_000)),
+            (WholeSuffix::Trong, "สองพันบาทถ้วน", None),
+            (WholeSuffix::Trong, "สองพันบาท", None),
+            (WholeSuffix::Omit, "สองพันบาท", Some(200_000)),
+            (WholeSuffix::Omit, "สองพันบาทถ้วน", None),
+            (WholeSuffix::Omit, "สองพันบาทห้าสตางค์", Some(200_005)),
+        ];
+
+        for &(suffix, text, satang) in &cases {
+            for strictness in [Strictness::Strict, Strictness::Lenient] {
+                assert_eq!(
+                    parse_words_styled(text, strictness, &BahtText::new().whole_suffix(suffix))
+                        .ok(),
+                    satang,
+                    "Fail case: {} {:?}",
+                    text,
+                    suffix
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_words_styled_errors() {
+        let cases = [
+            (WholeSuffix::Trong, "สองบาท", 18, "ตรง or satang"),
+            (WholeSuffix::Omit, "สองบาทถ้วน", 18, "satang or end of text"),
+        ];
+
+        for &(suffix, text, position, expected) in &cases {
+            assert_eq!(
+                parse_words_styled(
+                    text,
+                    Strictness::Strict,
+                    &BahtText::new().whole_suffix(suffix)
+                ),
+                Err(BahtTextError::InvalidWords { position, expected }),
+                "Fail case: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_words_negative_prefix() {
+        let style = BahtText::new().negative_prefix("ติดลบ ");
+        let parse = |text| parse_words_styled(text, Strictness::Strict, &style);
+
+        assert_eq!(parse("ติดลบห้าบาทถ้วน"), Ok(-500));
+        assert_eq!(parse(" ติดลบ ห้าบาทถ้วน"), Ok(-500));
+        assert_eq!(parse("ห้าบาทถ้วน"), Ok(500));
+        assert!(parse("ลบห้าบาทถ้วน").is_err());
+
+        let unsigned = BahtText::new().negative_prefix("");
+        assert_eq!(
+            parse_words_styled("ลบห้าบาทถ้วน", Strictness::Strict, &unsigned),
+            Err(BahtTextError::InvalidWords {
+                position: 0,
+                expected: "a Thai number word"
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_words_without_leading_one() {
+        let style = BahtText::new().leading_one(false);
+        let cases = [
+            ("ร้อยบาทถ้วน", Some(10_000)),
+            ("พันหนึ่งร้อยบาทถ้วน", Some(110_000)),
+            ("ล้านบาทถ้วน", Some(100_000_000)),
+            ("ล้านล้านบาทถ้วน", Some(100_000_000_000_000)),
+            ("ร้อยบาทยี่สิบห้าสตางค์", Some(10_025)),
+            ("หนึ่งบาทถ้วน", Some(100)),
+            ("สิบเอ็ดล้านบาทถ้วน", Some(1_100_000_000)),
+            ("หนึ่งร้อยบาทถ้วน", None),
+            ("หนึ่งล้านบาทถ้วน", None),
+            ("ร้อยร้อยบาทถ้วน", None),
+        ];
+
+        for &(text, satang) in &cases {
+            assert_eq!(
+                parse_words_styled(text, Strictness::Strict, &style).ok(),
+                satang,
+                "Fail case: {}",
+                text
+            );
+            let lenient = satang.or(match text {
+                "หนึ่งร้อยบาทถ้วน" => Some(10_000),
+                "หนึ่งล้านบาทถ้วน" => Some(100_000_000),
+                _ => None,
+            });
+            assert_eq!(
+                parse_words_styled(text, Strictness::Lenient, &style).ok(),
+                lenient,
+                "Fail case: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_words_satang_alone() {
+        let style = BahtText::new().zero_baht(false);
+        let parse = |text| parse_words_styled(text, Strictness::Strict, &style);
+
+        assert_eq!(parse("ห้าสิบสตางค์"), Ok(50));
+        assert_eq!(parse("ลบหนึ่งสตางค์"), Ok(-1));
+        assert_eq!(parse("ศูนย์บาทห้าสิบสตางค์"), Ok(50));
+        assert_eq!(parse("ศูนย์บาทถ้วน"), Ok(0));
+
+        let cases = [
+            ("ศูนย์สตางค์", 0, "satang from หนึ่ง to เก้าสิบเก้า"),
+            ("หนึ่งร้อยสตางค์", 0, "satang from หนึ่ง to เก้าสิบเก้า"),
+            ("ห้าสิบสตางค์บาท", 36, "end of text"),
+        ];
+
+        for &(text, position, expected) in &cases {
+            assert_eq!(
+                parse(text),
+                Err(BahtTextError::InvalidWords { position, expected }),
+                "Fail case: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_words_errors() {
+        let cases = [
+            ("", 0, "a number"),
+            ("บาทถ้วน", 0, "a number"),
+            ("หนึ่งร้อย", 27, "บาท"),
+            ("หนึ่งร้อยบาท", 36, "ถ้วน or satang"),
+            ("หนึ่งร้อยบาทสตางค์", 36, "ถ้วน or satang"),
+            ("หนึ่งร้อยบาทตรง", 36, "ถ้วน or satang"),
+            ("หนึ่งบาทลบ", 24, "a Thai number word"),
+            ("ห้าสิบสตางค์", 18, "บาท"),
+            ("หนึ่งบาทหนึ่ง", 39, "สตางค์"),
+            ("หนึ่งบาทถ้วนถ้วน", 36, "end of text"),
+            ("หนึ่งบาทศูนย์สตางค์", 24, "satang from หนึ่ง to เก้าสิบเก้า"),
+            ("หนึ่งบาทหนึ่งร้อยสตางค์", 24, "satang from หนึ่ง to เก้าสิบเก้า"),
+            ("หนึ่งdollar", 15, "a Thai number word"),
+            ("ร้อยบาทถ้วน", 0, "a digit from หนึ่ง to เก้า"),
+            ("หนึ่งสิบบาทถ้วน", 0, "สิบ, ยี่สิบ or a digit from สาม to เก้า"),
+            ("สองสิบบาทถ้วน", 0, "สิบ, ยี่สิบ or a digit from สาม to เก้า"),
+            ("ยี่บาทถ้วน", 0, "สิบ after ยี่"),
+            ("เอ็ดบาทถ้วน", 0, "หนึ่ง"),
+            ("สิบหนึ่งบาทถ้วน", 9, "เอ็ด"),
+            ("หนึ่งร้อยหนึ่งพันบาทถ้วน", 27, "a smaller place value"),
+            ("สามสามบาทถ้วน", 9, "บาท"),
+            ("หนึ่งล้านล้านศูนย์บาทถ้วน", 39, "บาท"),
+        ];
+
+        for &(text, position, expected) in &cases {
+            assert_eq!(
+                parse_words(text),
+                Err(BahtTextError::InvalidWords { position, expected }),
+                "Fail case: {}",
+                text
+            );
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! Serde support for [`Baht`], behind the `serde` feature.
+//!
+//! A `Baht` serializes as a string of digits such as `"1234.50"`, so no
+//! precision is lost in JSON. It deserializes from a string or a number, read
+//! the way [`words_from`](crate::words_from) reads it and rounded to the
+//! nearest satang, or from an object with an `"amount"` field such as the one
+//! [`with_text`] writes. Errors reading the amount become errors of the
+//! deserializer.
+//!
+//! A JSON number is only read as written if `serde_json` has its
+//! `arbitrary_precision` feature on. Otherwise any number with a fraction or
+//! beyond `u64` reaches us as an `f64`: decimals are read from its shortest
+//! digits, so a long fraction may already have lost some, and whole numbers
+//! above 2^53 are rejected, as the `f64` can no longer tell them apart. Send
+//! amounts as strings, or enable the feature, where that matters.
+//!
+//! Binary formats that are not self-describing, such as bincode, cannot say
+//! which of these comes next, so with them an amount is read back only in
+//! the form it was written.
+//!
+//! # Examples
+//! ```
+//! use bahttext::Baht;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Invoice {
+//!     subtotal: Baht,
+//!     #[serde(with = "bahttext::serde::with_text")]
+//!     total: Baht,
+//! }
+//!
+//! let invoice: Invoice = serde_json::from_str(r#"{"subtotal": 100, "total": "1,070"}"#).unwrap();
+//! assert_eq!(
+//!     serde_json::to_string(&invoice).unwrap(),
+//!     r#"{"subtotal":"100.00","total":{"amount":"1070.00","text":"หนึ่งพันเจ็ดสิบบาทถ้วน"}}"#
+//! );
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+use ::serde::de::{self, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::SerializeStruct;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Baht, BahtTextError};
+
+/// The key `serde_json` wraps numbers in when its `arbitrary_precision`
+/// feature is on, so that they reach us as written instead of as `f64`.
+const JSON_NUMBER_KEY: &str = "$serde_json::private::Number";
+
+/// The largest `f64` below which every whole number has its own value, 2^53.
+const MAX_EXACT_F64: f64 = 9_007_199_254_740_992.0;
+
+impl Serialize for Baht {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Baht {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(AmountVisitor)
+        } else {
+            deserializer.deserialize_str(AmountVisitor)
+        }
+    }
+}
+
+/// Writes an amount as `{"amount": "1234.50", "text": "..."}`.
+///
+/// Use it as `#[serde(with = "bahttext::serde::with_text")]` on a [`Baht`]
+/// field. Reading accepts the same object, or a plain string or number, and
+/// takes the amount from `"amount"` alone.
+pub mod with_text {
+    use super::*;
+
+    /// Serializes the amount together with its Thai text.
+    pub fn serialize<S: Serializer>(amount: &Baht, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut pair = serializer.serialize_struct("Baht", 2)?;
+        pair.serialize_field("amount", amount)?;
+        pair.serialize_field("text", &amount.words())?;
+        pair.end()
+    }
+
+    /// Deserializes an amount written by [`serialize`], or a plain amount.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Baht, D::Error> {
+        if deserializer.is_human_readable() {
+            Baht::deserialize(deserializer)
+        } else {
+            deserializer.deserialize_struct("Baht", &["amount", "text"], AmountVisitor)
+        }
+    }
+}
+
+struct AmountVisitor;
+
+impl AmountVisitor {
+    fn whole<E: de::Error>(baht: i128) -> Result<Baht, E> {
+        Baht::from_baht(baht).ok_or_else(|| E::custom(BahtTextError::AmountTooLarge))
+    }
+}
+
+impl<'de> Visitor<'de> for AmountVisitor {
+    type Value = Baht;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an amount of baht as a string or number")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Baht, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Baht, E> {
+        Self::whole(v.into())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Baht, E> {
+        Self::whole(v.into())
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Baht, E> {
+        Self::whole(v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Baht, E> {
+        let v = i128::try_from(v).map_err(|_| E::custom(BahtTextError::AmountTooLarge))?;
+        Self::whole(v)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Baht, E> {
+        // Above 2^53 neighbouring whole numbers share an f64, so the amount
+        // written was most likely rounded on the way
+        if v.abs() > MAX_EXACT_F64 {
+            return Err(E::custom(
+                "amount is too large to be exact as a floating-point number; \
+                 send it as a string",
+            ));
+        }
+        // The shortest digits that read back as the same f64
+        self.visit_str(&format!("{}", v))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Baht, A::Error> {
+        let mut amount = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == JSON_NUMBER_KEY {
+                return self.visit_str(&map.next_value::<String>()?);
+            }
+            if key == "amount" {
+                amount = Some(map.next_value::<Baht>()?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        amount.ok_or_else(|| de::Error::missing_field("amount"))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Baht, A::Error> {
+        let amount = seq
+            .next_element::<Baht>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &"an amount and its text"))?;
+        // The text is read rather than skipped, as binary formats cannot skip
+        seq.next_element::<String>()?;
+        Ok(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Invoice {
+        #[serde(with = "with_text")]
+        total: Baht,
+    }
+
+    #[test]
+    fn test_deserialize_amounts() {
+        let cases = [
+            (r#""1,234.56""#, 123_456),
+            (r#"" -0.005 ""#, -1),
+            ("100", 10_000),
+            ("-7", -700),
+            ("0.1", 10),
+            ("1234.565", 123_457),
+            ("1e3", 100_000),
+            (r#"{"amount": "2.5", "text": "anything"}"#, 250),
+        ];
+
+        for &(json, satang) in &cases {
+            assert_eq!(
+                serde_json::from_str::<Baht>(json).unwrap(),
+                Baht::from_satang(satang),
+                "Fail case: {}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        let cases = [
+            (r#""abc""#, "Failed to parse amount"),
+            (r#""1e40""#, "Amount is too large"),
+            ("true", "expected an amount of baht"),
+            (r#"{"text": "ศูนย์บาทถ้วน"}"#, "missing field `amount`"),
+        ];
+
+        for &(json, message) in &cases {
+            let err = serde_json::from_str::<Baht>(json).unwrap_err();
+            assert!(
+                err.to_string().contains(message),
+                "Fail case: {}: {}",
+                json,
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_inexact_floats() {
+        let visit = |v: f64| AmountVisitor.visit_f64::<de::value::Error>(v);
+
+        assert_eq!(
+            visit(MAX_EXACT_F64),
+            Ok(Baht::from_satang(900_719_925_474_099_200))
+        );
+        assert_eq!(visit(-0.5), Ok(Baht::from_satang(-50)));
+        for v in [18_446_744_073_709_551_616.0, -1e16, 1e300] {
+            let err = visit(v).unwrap_err();
+            assert!(
+                err.to_string().contains("too large to be exact"),
+                "Fail case: {}: {}",
+                v,
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        for satang in [0, -150, 123_456, i128::MIN, i128::MAX] {
+            let amount = Baht::from_satang(satang);
+            let bytes = bincode::serialize(&amount).unwrap();
+            assert_eq!(
+                bincode::deserialize::<Baht>(&bytes).unwrap(),
+                amount,
+                "Fail case: {}",
+                satang
+            );
+
+            let invoice = Invoice { total: amount };
+            let bytes = bincode::serialize(&invoice).unwrap();
+            assert_eq!(bincode::deserialize::<Invoice>(&bytes).unwrap(), invoice);
+        }
+    }
+
+    #[test]
+    fn test_with_text() {
+        let invoice = Invoice {
+            total: Baht::from_satang(-150),
+        };
+        let json = serde_json::to_string(&invoice).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"total":{"amount":"-1.50","text":"ลบหนึ่งบาทห้าสิบสตางค์"}}"#
+        );
+        assert_eq!(serde_json::from_str::<Invoice>(&json).unwrap(), invoice);
+        assert_eq!(
+            serde_json::from_str::<Invoice>(r#"{"total": 21}"#).unwrap(),
+            Invoice {
+                total: Baht::from_satang(2_100)
+            }
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::{words, Baht, BahtText, BahtTextError};
+
+/// Converts a value to Thai baht text.
+///
+/// Floating-point numbers are amounts of baht with satang in the fraction,
+/// and integers are whole baht. Implement [`try_to_baht_text_with`] for your
+/// own money types, usually by calling a method of the [`BahtText`] given, and
+/// the other methods follow.
+///
+/// [`try_to_baht_text_with`]: ToBahtText::try_to_baht_text_with
+///
+/// # Examples
+/// ```
+/// use bahttext::{BahtText, ToBahtText};
+///
+/// assert_eq!(1_234.5.to_baht_text(), "หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบสตางค์");
+/// assert_eq!(21u8.to_baht_text(), "ยี่สิบเอ็ดบาทถ้วน");
+///
+/// struct Cents(i64);
+///
+/// impl ToBahtText for Cents {
+///     fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, bahttext::BahtTextError> {
+///         options.words_from_satang(self.0.into())
+///     }
+/// }
+///
+/// let style = BahtText::new().leading_one(false);
+/// assert_eq!(Cents(100_050).try_to_baht_text_with(&style).unwrap(), "พันบาทห้าสิบสตางค์");
+/// ```
+pub trait ToBahtText {
+    /// Converts the value to Thai text with the given options.
+    ///
+    /// # Errors
+    /// Returns the errors of the [`BahtText`] method the value is converted with
+    fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, BahtTextError>;
+
+    /// Converts the value to Thai text with the default options.
+    ///
+    /// # Errors
+    /// Returns the errors of [`try_to_baht_text_with`](Self::try_to_baht_text_with)
+    fn try_to_baht_text(&self) -> Result<String, BahtTextError> {
+        self.try_to_baht_text_with(&BahtText::new())
+    }
+
+    /// Converts the value to Thai text with the default options.
+    ///
+    /// # Panics
+    /// Panics if [`try_to_baht_text`](Self::try_to_baht_text) fails. The
+    /// implementations in this crate don't: NaN and infinities saturate like
+    /// [`words`], and a `BigDecimal` exponent such as `1e5000` is written out
+    fn to_baht_text(&self) -> String {
+        self.try_to_baht_text()
+            .expect("the amount can be written with the default options")
+    }
+}
+
+impl ToBahtText for f64 {
+    fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, BahtTextError> {
+        options.try_words(*self)
+    }
+
+    fn to_baht_text(&self) -> String {
+        words(*self)
+    }
+}
+
+impl ToBahtText for f32 {
+    /// Reads the shortest digits of the `f32`, so `2.675f32` rounds up like
+    /// `2.675` does rather than down like its `f64` widening.
+    fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, BahtTextError> {
+        options.words_from(&format!("{}", self))
+    }
+
+    fn to_baht_text(&self) -> String {
+        let money = if self.is_nan() {
+            0.0
+        } else {
+            self.clamp(f32::MIN, f32::MAX)
+        };
+        money
+            .try_to_baht_text()
+            .expect("a finite amount can always be written")
+    }
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl ToBahtText for $t {
+            fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, BahtTextError> {
+                options.words_exact(*self < 0, self.unsigned_abs() as u128, 0)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl ToBahtText for $t {
+            fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, BahtTextError> {
+                options.words_exact(false, *self as u128, 0)
+            }
+        }
+    )*};
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl ToBahtText for Baht {
+    fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, BahtTextError> {
+        options.words_from_satang(self.satang())
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl ToBahtText for rust_decimal::Decimal {
+    fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, BahtTextError> {
+        options.words_from_decimal(*self)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl ToBahtText for bigdecimal::BigDecimal {
+    fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, BahtTextError> {
+        options.words_from_big_decimal(self)
+    }
+
+    fn to_baht_text(&self) -> String {
+        crate::words_from_big_decimal(self)
+            .expect("the digits of a BigDecimal can always be written")
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl ToBahtText for num_bigint::BigInt {
+    fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, BahtTextError> {
+        options.words_from_big_int(self)
+    }
+}
+
+impl<T: ToBahtText + ?Sized> ToBahtText for &T {
+    fn try_to_baht_text_with(&self, options: &BahtText<'_>) -> Result<String, BahtTextError> {
+        (**self).try_to_baht_text_with(options)
+    }
+
+    fn to_baht_text(&self) -> String {
+        (**self).to_baht_text()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{try_words, words_from};
+
+    #[test]
+    fn test_floats() {
+        for money in [0.0, 0.005, -1_234.56, 1e20, 2.675] {
+            assert_eq!(money.to_baht_text(), words(money), "Fail case: {}", money);
+            assert_eq!(money.try_to_baht_text(), try_words(money));
+        }
+
+        assert_eq!(2.675f32.to_baht_text(), "สองบาทหกสิบแปดสตางค์");
+        assert_eq!(0.1f32.to_baht_text(), "ศูนย์บาทสิบสตางค์");
+        assert_eq!(f64::NAN.to_baht_text(), "ศูนย์บาทถ้วน");
+        assert_eq!(f32::NAN.to_baht_text(), "ศูนย์บาทถ้วน");
+        assert_eq!(f32::NEG_INFINITY.to_baht_text(), f32::MIN.to_baht_text());
+        assert_eq!(
+            f32::INFINITY.try_to_baht_text(),
+            Err(BahtTextError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(0u8.to_baht_text(), "ศูนย์บาทถ้วน");
+        assert_eq!((-21i8).to_baht_text(), "ลบยี่สิบเอ็ดบาทถ้วน");
+        assert_eq!(1_000_000usize.to_baht_text(), "หนึ่งล้านบาทถ้วน");
+        assert_eq!(i8::MIN.to_baht_text(), "ลบหนึ่งร้อยยี่สิบแปดบาทถ้วน");
+        for baht in [i128::MIN, i128::MAX] {
+            assert_eq!(
+                baht.to_baht_text(),
+                words_from(&format!("{}", baht)).unwrap()
+            );
+        }
+        assert_eq!(
+            u128::MAX.to_baht_text(),
+            words_from(&format!("{}", u128::MAX)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let style = BahtText::new().leading_one(false).max_baht(1_000);
+        assert_eq!(1_000u16.try_to_baht_text_with(&style).unwrap(), "พันบาทถ้วน");
+        assert_eq!(
+            1_001u16.try_to_baht_text_with(&style),
+            Err(BahtTextError::AmountTooLarge)
+        );
+        assert_eq!(
+            Baht::from_satang(-150)
+                .try_to_baht_text_with(&style)
+                .unwrap(),
+            "ลบหนึ่งบาทห้าสิบสตางค์"
+        );
+        assert_eq!((&&5i32).to_baht_text(), "ห้าบาทถ้วน");
+    }
+
+    #[test]
+    #[cfg(feature = "bigdecimal")]
+    fn test_big_decimal_exponent() {
+        let amount: bigdecimal::BigDecimal = "1e5000".parse().unwrap();
+        let digits: bigdecimal::BigDecimal = format!("1{}", "0".repeat(5_000)).parse().unwrap();
+        assert_eq!(amount.to_baht_text(), digits.to_baht_text());
+        assert_eq!(Ok(amount.to_baht_text()), amount.try_to_baht_text());
+    }
+}
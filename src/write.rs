@@ -0,0 +1,323 @@
+use core::fmt::{self, Write as _};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{write_number, BahtText, BahtTextError, Rounding};
+
+/// Room for the shortest decimal form of any `f64`, the longest being the
+/// 326 characters of `5e-324`, plus a leading zero for a rounding carry.
+const F64_DIGITS: usize = 330;
+
+/// Writes a monetary amount in Thai text to `w`, without allocating.
+///
+/// The text is the same as [`words`](crate::words) returns: the amount is
+/// read through its shortest decimal representation and rounded to the
+/// nearest satang, halves away from zero.
+///
+/// # Errors
+/// Returns `fmt::Error` only if `w` does
+///
+/// # Examples
+/// ```
+/// use bahttext::write_words;
+///
+/// let mut text = String::from("ยอดชำระ: ");
+/// write_words(&mut text, 1_234.56).unwrap();
+/// assert_eq!(text, "ยอดชำระ: หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์");
+/// ```
+pub fn write_words<W: fmt::Write>(w: &mut W, money: f64) -> fmt::Result {
+    let money = match money {
+        m if m.is_nan() => 0.0,
+        m if m.is_infinite() => f64::MAX.copysign(m),
+        m => m,
+    };
+
+    let mut shortest = StackStr::<F64_DIGITS>::new();
+    write!(shortest, "{}", money.abs())?;
+    let (integer, fraction) = shortest
+        .as_str()
+        .split_once('.')
+        .unwrap_or((shortest.as_str(), ""));
+    let digit = |i: usize| fraction.as_bytes().get(i).map_or(0, |b| b - b'0');
+
+    let mut baht = StackStr::<F64_DIGITS>::new();
+    baht.write_str("0")?;
+    baht.write_str(integer)?;
+
+    let rest = fraction.bytes().skip(3).any(|b| b != b'0');
+    let mut satang = Rounding::HalfUp.round_satang(
+        money.is_sign_negative(),
+        digit(0) * 10 + digit(1),
+        digit(2),
+        rest,
+    );
+    if satang == 100 {
+        satang = 0;
+        baht.increment();
+    }
+
+    let negative = money.is_sign_negative() && (satang > 0 || baht.as_str() != "00");
+    BahtText::new().write_parts(w, negative, baht.as_str(), satang)
+}
+
+/// Writes a whole number in Thai words to `w`, without any currency words.
+///
+/// Negative numbers start with ลบ and zero is ศูนย์, as in the amounts
+/// [`write_words`] writes.
+///
+/// # Errors
+/// Returns `fmt::Error` only if `w` does
+///
+/// # Examples
+/// ```
+/// use bahttext::write_number_words;
+///
+/// let mut text = String::from("จำนวน ");
+/// write_number_words(&mut text, 101).unwrap();
+/// assert_eq!(text, "จำนวน หนึ่งร้อยเอ็ด");
+/// ```
+pub fn write_number_words<W: fmt::Write>(w: &mut W, number: i128) -> fmt::Result {
+    if number < 0 {
+        w.write_str("ลบ")?;
+    }
+
+    // The digits of an i128 fit in 39 bytes
+    let mut digits = StackStr::<40>::new();
+    write!(digits, "{}", number.unsigned_abs())?;
+    write_number(w, digits.as_str(), true)
+}
+
+/// Writes a monetary amount in Thai text into `buf`, returning the text written.
+///
+/// This needs no heap at all. Use [`words_len`] to size the buffer exactly;
+/// fixed-capacity strings such as `heapless::String` implement `fmt::Write`
+/// and can be given to [`write_words`] instead.
+///
+/// # Errors
+/// Returns `BahtTextError::BufferTooSmall` with the number of bytes needed if
+/// the text does not fit, in which case the contents of `buf` are unspecified
+///
+/// # Examples
+/// ```
+/// use bahttext::{words_into, words_len, BahtTextError};
+///
+/// let mut buf = [0u8; 64];
+/// assert_eq!(words_into(&mut buf, 21.0).unwrap(), "ยี่สิบเอ็ดบาทถ้วน");
+///
+/// assert_eq!(words_len(1_234.56), 123);
+/// assert_eq!(
+///     words_into(&mut buf, 1_234.56).unwrap_err(),
+///     BahtTextError::BufferTooSmall { required: 123 }
+/// );
+/// ```
+pub fn words_into(buf: &mut [u8], money: f64) -> Result<&str, BahtTextError> {
+    let mut writer = SliceWriter { buf, len: 0 };
+    if write_words(&mut writer, money).is_err() {
+        return Err(BahtTextError::BufferTooSmall {
+            required: words_len(money),
+        });
+    }
+
+    let SliceWriter { buf, len } = writer;
+    Ok(core::str::from_utf8(&buf[..len]).expect("only whole strings are written"))
+}
+
+/// Returns the length in bytes of the UTF-8 text [`words`](crate::words)
+/// gives for `money`, without writing it anywhere.
+pub fn words_len(money: f64) -> usize {
+    let mut counter = Counter(0);
+    write_words(&mut counter, money).expect("counting never fails");
+    counter.0
+}
+
+/// A `fmt::Write` that fills a byte slice and fails once it is full.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// A `fmt::Write` that only counts the bytes written.
+struct Counter(usize);
+
+impl fmt::Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Writes a monetary amount in Thai text to an `io::Write`, as UTF-8.
+///
+/// See [`write_words`].
+///
+/// # Errors
+/// Returns any error from writing to `w`
+///
+/// # Examples
+/// ```
+/// use bahttext::write_words_io;
+///
+/// let mut out = Vec::new();
+/// write_words_io(&mut out, -0.5).unwrap();
+/// assert_eq!(out, "ลบศูนย์บาทห้าสิบสตางค์".as_bytes());
+/// ```
+#[cfg(feature = "std")]
+pub fn write_words_io<W: io::Write>(w: &mut W, money: f64) -> io::Result<()> {
+    write!(w, "{}", Words(money))
+}
+
+/// Displays an amount in Thai words, so `io::Write` can reuse the
+/// `fmt::Write` path.
+#[cfg(feature = "std")]
+struct Words(f64);
+
+#[cfg(feature = "std")]
+impl fmt::Display for Words {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_words(f, self.0)
+    }
+}
+
+/// A string held on the stack.
+pub(crate) struct StackStr<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StackStr<N> {
+    pub(crate) fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).expect("only whole strings are written")
+    }
+
+    /// Adds one to the ASCII digits held, which must start with a zero so
+    /// there is room to carry.
+    fn increment(&mut self) {
+        for digit in self.buf[..self.len].iter_mut().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                return;
+            }
+        }
+    }
+}
+
+impl<const N: usize> fmt::Write for StackStr<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > N {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::try_words;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_write_words_matches_try_words() {
+        let samples = [
+            0.0,
+            -0.0,
+            -0.001,
+            0.005,
+            1.005,
+            9.995,
+            -51.995,
+            99.999,
+            999_999.995,
+            12_345.678,
+            1e15 + 0.125,
+            1e20,
+            1e300,
+            f64::MAX,
+            f64::MIN,
+            f64::MIN_POSITIVE,
+            5e-324,
+        ];
+
+        for money in samples {
+            let mut text = String::new();
+            write_words(&mut text, money).unwrap();
+            assert_eq!(text, try_words(money).unwrap(), "Fail case: {}", money);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_words_into() {
+        for money in [0.0, -0.5, 21.01, 1_234.56, 1e20, f64::MAX] {
+            let expected = try_words(money).unwrap();
+            assert_eq!(words_len(money), expected.len(), "Fail case: {}", money);
+
+            let mut buf = vec![0u8; expected.len()];
+            assert_eq!(words_into(&mut buf, money), Ok(expected.as_str()));
+
+            let mut short = vec![0u8; expected.len() - 1];
+            assert_eq!(
+                words_into(&mut short, money),
+                Err(BahtTextError::BufferTooSmall {
+                    required: expected.len()
+                })
+            );
+        }
+
+        assert_eq!(
+            words_into(&mut [], 1.0),
+            Err(BahtTextError::BufferTooSmall { required: 36 })
+        );
+    }
+
+    #[test]
+    fn test_words_into_stack_buffer() {
+        let mut buf = [0u8; 128];
+        assert_eq!(
+            words_into(&mut buf, 1_234.56),
+            Ok("หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์")
+        );
+        assert_eq!(words_into(&mut buf, -0.995), Ok("ลบหนึ่งบาทถ้วน"));
+        assert_eq!(
+            words_into(&mut buf[..35], 1.0),
+            Err(BahtTextError::BufferTooSmall { required: 36 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_write_words_io() {
+        let mut out = Vec::new();
+        write_words_io(&mut out, 21.01).unwrap();
+        write_words_io(&mut out, 100.0).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "ยี่สิบเอ็ดบาทหนึ่งสตางค์หนึ่งร้อยบาทถ้วน"
+        );
+    }
+}
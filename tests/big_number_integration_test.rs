@@ -0,0 +1,89 @@
+use bahttext::{
+    words_from, words_from_big_decimal, words_from_big_int, BahtText, BahtTextError, Rounding,
+};
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+
+#[test]
+fn test_words_from_big_decimal_matches_words_from() {
+    let cases = [
+        "0",
+        "-0.005",
+        "1234.565",
+        "0.00000000000000000000000000000000000000001",
+        "123456789012345678901234567890123456789012345678901234567890.99",
+        "-1e100",
+        "1.5e-2",
+        "12e4096",
+    ];
+
+    for input in cases {
+        let amount: BigDecimal = input.parse().unwrap();
+        assert_eq!(
+            words_from_big_decimal(&amount).unwrap(),
+            words_from(input).unwrap(),
+            "Fail case: {}",
+            input
+        );
+    }
+
+    // Beyond the exponent text input allows, the same as the BigInt
+    let amount: BigDecimal = "-1e5000".parse().unwrap();
+    assert_eq!(
+        words_from_big_decimal(&amount).unwrap(),
+        words_from_big_int(&-BigInt::from(10).pow(5_000))
+    );
+    assert_eq!(words_from("1e5000"), Err(BahtTextError::AmountTooLarge));
+
+    let amount: BigDecimal = "1e999999999999".parse().unwrap();
+    assert_eq!(
+        BahtText::new()
+            .max_baht(u128::MAX)
+            .words_from_big_decimal(&amount),
+        Err(BahtTextError::AmountTooLarge)
+    );
+}
+
+#[test]
+fn test_words_from_big_decimal_options() {
+    let amount: BigDecimal = "-2.675".parse().unwrap();
+    let style = BahtText::new().rounding(Rounding::HalfEven);
+    assert_eq!(
+        style.words_from_big_decimal(&amount).unwrap(),
+        "ลบสองบาทหกสิบแปดสตางค์"
+    );
+    assert_eq!(
+        BahtText::new().strict(true).words_from_big_decimal(&amount),
+        Err(BahtTextError::ExcessPrecision { decimals: 3 })
+    );
+}
+
+#[test]
+fn test_words_from_big_int() {
+    let cases = [
+        (BigInt::from(0), "ศูนย์บาทถ้วน"),
+        (BigInt::from(-21), "ลบยี่สิบเอ็ดบาทถ้วน"),
+        (
+            BigInt::from(10).pow(60),
+            "หนึ่งล้านล้านล้านล้านล้านล้านล้านล้านล้านล้านบาทถ้วน",
+        ),
+    ];
+
+    for (baht, expected) in &cases {
+        assert_eq!(words_from_big_int(baht), *expected, "Fail case: {}", baht);
+    }
+
+    // Past the i128 and u128 satang that the other functions are limited to
+    let baht = BigInt::from(u128::MAX) + 1;
+    assert_eq!(
+        words_from_big_int(&baht),
+        words_from(&baht.to_string()).unwrap()
+    );
+
+    assert_eq!(
+        BahtText::new()
+            .max_baht(1_000_000)
+            .words_from_big_int(&BigInt::from(1_000_001)),
+        Err(BahtTextError::AmountTooLarge)
+    );
+}
@@ -0,0 +1,269 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn bahttext(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_bahttext"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_cli_arguments() {
+    let output = bahttext(&["1,234.56", "-100"], "");
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์\nลบหนึ่งร้อยบาทถ้วน\n"
+    );
+}
+
+#[test]
+fn test_cli_stdin() {
+    let output = bahttext(&[], "21\n0.5\r\n");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "ยี่สิบเอ็ดบาทถ้วน\nศูนย์บาทห้าสิบสตางค์\n");
+}
+
+#[test]
+fn test_cli_style() {
+    let output = bahttext(
+        &[
+            "--suffix",
+            "trong",
+            "--negative-prefix",
+            "ติดลบ",
+            "--no-leading-one",
+            "--rounding",
+            "nearest-quarter",
+            "-1000.1",
+            "1000",
+        ],
+        "",
+    );
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "ติดลบพันบาทตรง\nพันบาทตรง\n");
+
+    let output = bahttext(&["--spreadsheet", "0.1249999999999999"], "");
+    assert_eq!(stdout(&output), "สิบสามสตางค์\n");
+}
+
+#[test]
+fn test_cli_parse() {
+    let output = bahttext(&["--parse"], "หนึ่งร้อยบาทห้าสิบสตางค์\nลบศูนย์บาทห้าสิบสตางค์\n");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "100.50\n-0.50\n");
+
+    let output = bahttext(&["--parse", "--no-zero-baht", "ลบห้าสิบสตางค์"], "");
+    assert_eq!(stdout(&output), "-0.50\n");
+
+    let output = bahttext(&["--parse", "--lenient", "ร้อยบาทถ้วน"], "");
+    assert_eq!(stdout(&output), "100.00\n");
+
+    let output = bahttext(&["--parse", "--suffix", "trong", "หนึ่งร้อยบาทตรง"], "");
+    assert_eq!(stdout(&output), "100.00\n");
+}
+
+#[test]
+fn test_cli_parse_round_trip() {
+    let styles: [&[&str]; 5] = [
+        &[],
+        &["--negative-prefix", "ติดลบ"],
+        &["--no-leading-one"],
+        &["--suffix", "none", "--no-zero-baht"],
+        &[
+            "--spreadsheet",
+            "--suffix",
+            "trong",
+            "--negative-prefix",
+            "ติดลบ ",
+            "--no-leading-one",
+        ],
+    ];
+    let amounts = "-5.00\n100.00\n0.50\n-0.01\n1000000.00\n1100.25\n";
+
+    for style in styles {
+        let output = bahttext(style, amounts);
+        assert!(output.status.success(), "Fail case: {:?}", style);
+
+        let args: Vec<&str> = ["--parse"].iter().chain(style).copied().collect();
+        let parsed = bahttext(&args, stdout(&output));
+        assert!(parsed.status.success(), "Fail case: {:?}", style);
+        assert_eq!(stdout(&parsed), amounts, "Fail case: {:?}", style);
+    }
+}
+
+#[test]
+fn test_cli_errors() {
+    let output = bahttext(&["100", "abc", "200"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "หนึ่งร้อยบาทถ้วน\n");
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "bahttext: \"abc\": Failed to parse amount: invalid amount literal\n"
+    );
+
+    let output = bahttext(&["--strict", "12.345"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "bahttext: \"12.345\": Amount has 3 decimal places, more than 2\n"
+    );
+
+    let output = bahttext(&["--parse", "ร้อยบาทถ้วน"], "");
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = bahttext(&["--parse", "หนึ่งร้อยบาท"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "bahttext: \"หนึ่งร้อยบาท\": Invalid Thai words at byte 36: expected ถ้วน or satang\n"
+    );
+
+    let output = bahttext(&["--parse", "ห้าสิบสตางค์"], "");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_cli_csv() {
+    let output = bahttext(
+        &["--csv", "amount"],
+        "id,amount\n1,100\n2,abc\n3,\"1,234.5\"\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "id,amount,amount_text\n1,100,หนึ่งร้อยบาทถ้วน\n2,abc,\n3,\"1,234.5\",หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบสตางค์\n"
+    );
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "bahttext: line 3: \"abc\": Failed to parse amount: invalid amount literal\n\
+         bahttext: 1 row could not be converted\n"
+    );
+
+    let output = bahttext(
+        &["--csv", "2", "--output-column", "text"],
+        "id,amount\n1,21\n",
+    );
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "id,amount,text\n1,21,ยี่สิบเอ็ดบาทถ้วน\n");
+}
+
+#[test]
+fn test_cli_tsv() {
+    let output = bahttext(&["--tsv", "2", "--no-header"], "1\t21\n2\t0.5\n");
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "1\t21\tยี่สิบเอ็ดบาทถ้วน\n2\t0.5\tศูนย์บาทห้าสิบสตางค์\n"
+    );
+
+    let output = bahttext(&["--tsv", "amount"], "name\tamount\n\"Acme\" Ltd\t5\n");
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "name\tamount\tamount_text\n\"Acme\" Ltd\t5\tห้าบาทถ้วน\n"
+    );
+}
+
+#[test]
+fn test_cli_table_errors() {
+    let output = bahttext(&["--csv", "amount", "--fail-fast"], "amount\nx\n5\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "amount,amount_text\n");
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "bahttext: line 2: \"x\": Failed to parse amount: invalid amount literal\n"
+    );
+
+    let output = bahttext(&["--csv", "total"], "amount\n5\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "bahttext: no column \"total\" in the table\n"
+    );
+
+    let output = bahttext(&["--csv", "amount"], "a,amount\nz,3,extra\ny,4\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "a,amount,amount_text\nz,3,extra\ny,4,สี่บาทถ้วน\n"
+    );
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "bahttext: line 2: 3 columns, more than the 2 of the header\n\
+         bahttext: 1 row could not be converted\n"
+    );
+
+    let output = bahttext(&["--csv", "2", "--no-header"], "1,21\n2,5,x\n3\n4,1\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "1,21,ยี่สิบเอ็ดบาทถ้วน\n2,5,x\n3,,\n4,1,หนึ่งบาทถ้วน\n"
+    );
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "bahttext: line 2: 3 columns, more than the 2 of the first row\n\
+         bahttext: line 3: no column 2\n\
+         bahttext: 2 rows could not be converted\n"
+    );
+
+    for args in [["--output-column", "text"], ["--no-header", "1"]] {
+        let output = bahttext(&args, "");
+        assert_eq!(output.status.code(), Some(2), "Fail case: {:?}", args);
+    }
+}
+
+#[test]
+fn test_cli_jsonl() {
+    let output = bahttext(
+        &["--jsonl", "/total"],
+        "{\"id\":1,\"total\":1234.565}\n{\"id\":2,\"total\":\"1,000\"}\n{\"id\":3}\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "{\"id\":1,\"total\":1234.565,\"total_text\":\"หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบเจ็ดสตางค์\"}\n\
+         {\"id\":2,\"total\":\"1,000\",\"total_text\":\"หนึ่งพันบาทถ้วน\"}\n\
+         {\"id\":3}\n"
+    );
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "bahttext: line 3: no field /total\nbahttext: 1 row could not be converted\n"
+    );
+
+    let output = bahttext(
+        &["--jsonl", "/invoice/a~1b", "--output-field", "text"],
+        "{\"invoice\":{\"a/b\":0.5}}\n",
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "{\"invoice\":{\"a/b\":0.5,\"text\":\"ศูนย์บาทห้าสิบสตางค์\"}}\n"
+    );
+
+    let output = bahttext(
+        &["--jsonl", "/total", "--fail-fast"],
+        "{\"total\":true}\n{}\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "bahttext: line 1: /total is not a number or string\n"
+    );
+}
@@ -0,0 +1,46 @@
+use bahttext::{number_words, words_exact, write_number_words};
+
+#[test]
+fn test_number_words_integration() {
+    let cases = [
+        (0, "ศูนย์"),
+        (1, "หนึ่ง"),
+        (10, "สิบ"),
+        (11, "สิบเอ็ด"),
+        (20, "ยี่สิบ"),
+        (21, "ยี่สิบเอ็ด"),
+        (101, "หนึ่งร้อยเอ็ด"),
+        (1_234, "หนึ่งพันสองร้อยสามสิบสี่"),
+        (1_000_000, "หนึ่งล้าน"),
+        (21_000_021, "ยี่สิบเอ็ดล้านยี่สิบเอ็ด"),
+        (1_000_000_000_000, "หนึ่งล้านล้าน"),
+        (-5, "ลบห้า"),
+        (-1_234, "ลบหนึ่งพันสองร้อยสามสิบสี่"),
+    ];
+
+    for &(number, expected) in &cases {
+        assert_eq!(number_words(number), expected, "Fail case: {}", number);
+    }
+}
+
+#[test]
+fn test_number_words_matches_baht_words() {
+    for number in [0, 7, 99, 100, 1_001, 999_999, 1_000_001, 123_456_789_012] {
+        let baht = words_exact(false, number as u128, 0).unwrap();
+        assert_eq!(
+            format!("{}บาทถ้วน", number_words(number)),
+            baht,
+            "Fail case: {}",
+            number
+        );
+    }
+}
+
+#[test]
+fn test_write_number_words() {
+    let mut text = String::from("หน้า ");
+    write_number_words(&mut text, 12).unwrap();
+    text.push_str(" จาก ");
+    write_number_words(&mut text, 300).unwrap();
+    assert_eq!(text, "หน้า สิบสอง จาก สามร้อย");
+}
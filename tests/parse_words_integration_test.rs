@@ -0,0 +1,93 @@
+use bahttext::{parse_words, words, words_from, BahtTextError};
+
+#[test]
+fn test_parse_words_integration() {
+    assert_eq!(parse_words("ศูนย์บาทถ้วน").unwrap(), 0);
+    assert_eq!(parse_words("หนึ่งบาทถ้วน").unwrap(), 100);
+    assert_eq!(parse_words("สิบเอ็ดบาทถ้วน").unwrap(), 1_100);
+    assert_eq!(parse_words("ยี่สิบเอ็ดบาทถ้วน").unwrap(), 2_100);
+    assert_eq!(parse_words("ศูนย์บาทห้าสิบสตางค์").unwrap(), 50);
+    assert_eq!(
+        parse_words("หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์").unwrap(),
+        123_456
+    );
+    assert_eq!(parse_words("ลบหนึ่งร้อยบาทห้าสิบสตางค์").unwrap(), -10_050);
+}
+
+#[test]
+fn test_parse_words_large_numbers() {
+    assert_eq!(parse_words("หนึ่งล้านเอ็ดบาทถ้วน").unwrap(), 100_000_100);
+    assert_eq!(parse_words("สิบล้านเอ็ดบาทถ้วน").unwrap(), 1_000_000_100);
+    assert_eq!(
+        parse_words("หนึ่งพันสองร้อยสามสิบสี่ล้านห้าแสนหกหมื่นเจ็ดพันแปดร้อยเก้าสิบบาทถ้วน").unwrap(),
+        123_456_789_000
+    );
+    assert_eq!(parse_words("หนึ่งล้านล้านบาทถ้วน").unwrap(), 100_000_000_000_000);
+    assert_eq!(
+        parse_words("หนึ่งล้านเอ็ดล้านบาทถ้วน").unwrap(),
+        100_000_100_000_000
+    );
+}
+
+#[test]
+fn test_parse_words_reverses_words() {
+    for money in [
+        0.01,
+        1.0,
+        10.5,
+        101.0,
+        2_500.25,
+        1_000_000.01,
+        9_999_999_999_999.99,
+    ] {
+        let satang = (money * 100.0_f64).round() as i128;
+        assert_eq!(
+            parse_words(&words(money)).unwrap(),
+            satang,
+            "Fail case: {}",
+            money
+        );
+        assert_eq!(
+            parse_words(&words(-money)).unwrap(),
+            -satang,
+            "Fail case: {}",
+            -money
+        );
+    }
+
+    let text = words_from("870,886,734,867,267").unwrap();
+    assert_eq!(parse_words(&text).unwrap(), 87_088_673_486_726_700);
+}
+
+#[test]
+fn test_parse_words_reports_position() {
+    // The cheque is missing its closing ถ้วน
+    let text = "สองพันห้าร้อยบาท";
+    assert_eq!(
+        parse_words(text).unwrap_err(),
+        BahtTextError::InvalidWords {
+            position: text.len(),
+            expected: "ถ้วน or satang",
+        }
+    );
+
+    // Digits after บาท that are not satang
+    let text = "สองพันบาทสองพันสตางค์";
+    assert_eq!(
+        parse_words(text).unwrap_err(),
+        BahtTextError::InvalidWords {
+            position: "สองพันบาท".len(),
+            expected: "satang from หนึ่ง to เก้าสิบเก้า",
+        }
+    );
+
+    // Something that is not Thai number text
+    let text = "หนึ่งพันบาท (THB 1,000)";
+    assert_eq!(
+        parse_words(text).unwrap_err(),
+        BahtTextError::InvalidWords {
+            position: "หนึ่งพันบาท ".len(),
+            expected: "a Thai number word",
+        }
+    );
+}
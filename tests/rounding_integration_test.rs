@@ -0,0 +1,33 @@
+use bahttext::{words, BahtText, Rounding};
+
+#[test]
+fn test_rounding_modes_from_f64() {
+    let cases = [
+        (Rounding::HalfUp, 2.675, "สองบาทหกสิบแปดสตางค์"),
+        (Rounding::HalfEven, 2.675, "สองบาทหกสิบแปดสตางค์"),
+        (Rounding::HalfEven, 2.665, "สองบาทหกสิบหกสตางค์"),
+        (Rounding::Down, -10.001, "ลบสิบบาทหนึ่งสตางค์"),
+        (Rounding::Up, 10.001, "สิบบาทหนึ่งสตางค์"),
+        (Rounding::TowardZero, -10.009, "ลบสิบบาทถ้วน"),
+        (Rounding::NearestQuarter, 10.13, "สิบบาทยี่สิบห้าสตางค์"),
+        (Rounding::NearestQuarter, 10.88, "สิบเอ็ดบาทถ้วน"),
+    ];
+
+    for &(rounding, money, expected) in &cases {
+        let style = BahtText::new().rounding(rounding);
+        assert_eq!(
+            style.try_words(money).unwrap(),
+            expected,
+            "Fail case: {} {:?}",
+            money,
+            rounding
+        );
+    }
+}
+
+#[test]
+fn test_default_rounding_matches_words() {
+    for money in [0.005, 1.005, -51.995, 12.345, 1e15 + 0.125] {
+        assert_eq!(BahtText::new().try_words(money).unwrap(), words(money));
+    }
+}
@@ -0,0 +1,71 @@
+use bahttext::{words_from, words_from_decimal, BahtText, BahtTextError, Rounding};
+use rust_decimal::Decimal;
+
+#[test]
+fn test_words_from_decimal_matches_words_from() {
+    let cases = [
+        "0",
+        "0.005",
+        "-0.005",
+        "1234.565",
+        "-1,000,000.25",
+        "0.0000000000000000000000000001",
+        "79228162514264337593543950335",
+        "-79228162514264337593543950335",
+        "7922816251426433759354395033.5",
+    ];
+
+    for input in cases {
+        let amount: Decimal = input.replace(',', "").parse().unwrap();
+        assert_eq!(
+            words_from_decimal(amount).unwrap(),
+            words_from(input).unwrap(),
+            "Fail case: {}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_words_from_decimal_options() {
+    let amount = Decimal::new(-100_125, 3);
+    let cases = [
+        (Rounding::HalfUp, "ลบหนึ่งร้อยบาทสิบสามสตางค์"),
+        (Rounding::HalfEven, "ลบหนึ่งร้อยบาทสิบสองสตางค์"),
+        (Rounding::Down, "ลบหนึ่งร้อยบาทสิบสามสตางค์"),
+        (Rounding::TowardZero, "ลบหนึ่งร้อยบาทสิบสองสตางค์"),
+        (Rounding::NearestQuarter, "ลบหนึ่งร้อยบาทยี่สิบห้าสตางค์"),
+    ];
+
+    for &(rounding, expected) in &cases {
+        let style = BahtText::new().rounding(rounding);
+        assert_eq!(
+            style.words_from_decimal(amount).unwrap(),
+            expected,
+            "Fail case: {:?}",
+            rounding
+        );
+    }
+
+    // Trailing zeros are not extra precision
+    let strict = BahtText::new().strict(true);
+    assert_eq!(
+        strict.words_from_decimal(Decimal::new(150, 3)).unwrap(),
+        "ศูนย์บาทสิบห้าสตางค์"
+    );
+    assert_eq!(
+        strict.words_from_decimal(amount),
+        Err(BahtTextError::ExcessPrecision { decimals: 3 })
+    );
+    assert_eq!(
+        BahtText::new().max_baht(100).words_from_decimal(amount),
+        Err(BahtTextError::AmountTooLarge)
+    );
+    assert_eq!(
+        BahtText::new()
+            .max_baht(100)
+            .words_from_decimal(Decimal::new(-100_004, 3))
+            .unwrap(),
+        "ลบหนึ่งร้อยบาทถ้วน"
+    );
+}
@@ -0,0 +1,83 @@
+use bahttext::{words, BahtText, Strictness};
+
+const CORPUS: &str = include_str!("spreadsheet_corpus.tsv");
+
+struct Row {
+    amount: &'static str,
+    spreadsheet: &'static str,
+    default: &'static str,
+    source: &'static str,
+    note: &'static str,
+}
+
+fn corpus() -> impl Iterator<Item = Row> {
+    CORPUS
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.is_empty())
+        .map(|line| {
+            let columns: Vec<&str> = line.split('\t').collect();
+            assert_eq!(columns.len(), 5, "Bad corpus line: {}", line);
+            Row {
+                amount: columns[0],
+                spreadsheet: columns[1],
+                default: columns[2],
+                source: columns[3],
+                note: columns[4],
+            }
+        })
+}
+
+#[test]
+fn test_spreadsheet_corpus() {
+    let style = BahtText::spreadsheet();
+
+    for row in corpus() {
+        let money: f64 = row.amount.parse().unwrap();
+        assert_eq!(
+            style.try_words(money).unwrap(),
+            row.spreadsheet,
+            "Fail case: {}",
+            row.amount
+        );
+        assert_eq!(
+            style.words_from(row.amount).unwrap(),
+            row.spreadsheet,
+            "Fail case: {}",
+            row.amount
+        );
+        assert_eq!(words(money), row.default, "Fail case: {}", row.amount);
+        assert_eq!(
+            row.note.starts_with("same"),
+            row.spreadsheet == row.default,
+            "Note does not match the texts: {}",
+            row.amount
+        );
+    }
+}
+
+#[test]
+fn test_spreadsheet_corpus_sources() {
+    for row in corpus() {
+        let known = row.source == "unverified"
+            || row.source.split("; ").all(|source| {
+                let mut parts = source.splitn(2, ' ');
+                let app = parts.next().unwrap();
+                let version = parts.next().unwrap_or("");
+                (app == "excel" || app == "libreoffice") && !version.trim().is_empty()
+            });
+        assert!(known, "Bad source for {}: {:?}", row.amount, row.source);
+    }
+}
+
+#[test]
+fn test_spreadsheet_text_can_be_parsed() {
+    for row in corpus() {
+        let satang = BahtText::spreadsheet()
+            .parse_words(row.spreadsheet, Strictness::Strict)
+            .unwrap();
+        let expected = BahtText::spreadsheet()
+            .try_words(satang as f64 / 100.0)
+            .unwrap();
+        assert_eq!(expected, row.spreadsheet, "Fail case: {}", row.amount);
+    }
+}
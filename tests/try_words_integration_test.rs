@@ -0,0 +1,59 @@
+use bahttext::{try_words, try_words_with_limit, words, words_from_with_limit, BahtTextError};
+
+#[test]
+fn test_try_words_integration() {
+    assert_eq!(try_words(0.0).unwrap(), "ศูนย์บาทถ้วน");
+    assert_eq!(try_words(21.0).unwrap(), "ยี่สิบเอ็ดบาทถ้วน");
+    assert_eq!(try_words(0.50).unwrap(), "ศูนย์บาทห้าสิบสตางค์");
+    assert_eq!(
+        try_words(-1_234.56).unwrap(),
+        "ลบหนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์"
+    );
+    assert_eq!(try_words(1_000_000_000_000.0).unwrap(), "หนึ่งล้านล้านบาทถ้วน");
+}
+
+#[test]
+fn test_try_words_matches_words() {
+    for money in [0.0, 0.01, 1.0, 1.25, 100.75, 9012.34, -51.995, 1_234_567.89] {
+        assert_eq!(
+            try_words(money).unwrap(),
+            words(money),
+            "Fail case: {}",
+            money
+        );
+    }
+}
+
+#[test]
+fn test_try_words_errors() {
+    assert_eq!(
+        try_words(f64::NAN).unwrap_err(),
+        BahtTextError::InvalidNumber
+    );
+    assert_eq!(
+        try_words(f64::INFINITY).unwrap_err(),
+        BahtTextError::InvalidNumber
+    );
+}
+
+#[test]
+fn test_caller_supplied_limit() {
+    const MAX_TRANSFER: u128 = 2_000_000;
+
+    assert_eq!(
+        try_words_with_limit(2_000_000.0, MAX_TRANSFER).unwrap(),
+        "สองล้านบาทถ้วน"
+    );
+    assert_eq!(
+        try_words_with_limit(2_000_000.01, MAX_TRANSFER).unwrap_err(),
+        BahtTextError::AmountTooLarge
+    );
+    assert_eq!(
+        words_from_with_limit("-2,000,000", MAX_TRANSFER).unwrap(),
+        "ลบสองล้านบาทถ้วน"
+    );
+    assert_eq!(
+        words_from_with_limit("-2,000,001", MAX_TRANSFER).unwrap_err(),
+        BahtTextError::AmountTooLarge
+    );
+}
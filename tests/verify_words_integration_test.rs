@@ -0,0 +1,56 @@
+use bahttext::{verify_words, words, BahtTextError, Strictness, Verification};
+
+#[test]
+fn test_verify_words_integration() {
+    for money in [0.0, 0.5, 21.0, 101.0, 1_234.56, 1_000_001.0, -100.5] {
+        assert_eq!(
+            verify_words(money, &words(money), Strictness::Strict).unwrap(),
+            Verification::Match,
+            "Fail case: {}",
+            money
+        );
+    }
+}
+
+#[test]
+fn test_verify_words_mismatch() {
+    // Invoice total 12,500.00 with the words of 12,050.00
+    assert_eq!(
+        verify_words(12_500.0, "หนึ่งหมื่นสองพันห้าสิบบาทถ้วน", Strictness::Strict).unwrap(),
+        Verification::Mismatch { found: 1_205_000 }
+    );
+
+    // The sign is part of the amount
+    assert_eq!(
+        verify_words(100.0, "ลบหนึ่งร้อยบาทถ้วน", Strictness::Strict).unwrap(),
+        Verification::Mismatch { found: -10_000 }
+    );
+}
+
+#[test]
+fn test_verify_words_strictness() {
+    let texts = [
+        "ร้อยเอ็ดบาทถ้วน",
+        "หนึ่งร้อยหนึ่งบาทถ้วน",
+        "ร้อยหนึ่งบาทถ้วน",
+        "หนึ่ง ร้อย เอ็ด บาท ถ้วน",
+    ];
+
+    for text in texts {
+        assert_eq!(
+            verify_words(101.0, text, Strictness::Lenient).unwrap(),
+            Verification::Match,
+            "Fail case: {}",
+            text
+        );
+    }
+
+    assert_eq!(
+        verify_words(101.0, "หนึ่ง ร้อย เอ็ด บาท ถ้วน", Strictness::Strict).unwrap(),
+        Verification::Match
+    );
+    assert!(matches!(
+        verify_words(101.0, "ร้อยเอ็ดบาทถ้วน", Strictness::Strict).unwrap_err(),
+        BahtTextError::InvalidWords { position: 0, .. }
+    ));
+}
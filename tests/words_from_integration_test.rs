@@ -55,3 +55,57 @@ fn test_negative_numbers() {
     assert_eq!(words_from("-100.50").unwrap(), "ลบหนึ่งร้อยบาทห้าสิบสตางค์");
     assert_eq!(words_from("-1,234.56").unwrap(), "ลบหนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์");
 }
+
+#[test]
+fn test_exact_decimal_precision() {
+    // Beyond the 15-17 significant digits an f64 can hold
+    assert_eq!(
+        words_from("1,234,567,890,123.45").unwrap(),
+        "หนึ่งล้านสองแสนสามหมื่นสี่พันห้าร้อยหกสิบเจ็ดล้านแปดแสนเก้าหมื่นหนึ่งร้อยยี่สิบสามบาทสี่สิบห้าสตางค์"
+    );
+    assert_eq!(
+        words_from("92233720368547758.07").unwrap(),
+        "เก้าหมื่นสองพันสองร้อยสามสิบสามล้านเจ็ดแสนสองหมื่นสามร้อยหกสิบแปดล้านห้าแสนสี่หมื่นเจ็ดพันเจ็ดร้อยห้าสิบแปดบาทเจ็ดสตางค์"
+    );
+
+    // Rounded as written, not as the nearest binary fraction
+    assert_eq!(words_from("1.005").unwrap(), "หนึ่งบาทหนึ่งสตางค์");
+    assert_eq!(words_from("2.675").unwrap(), "สองบาทหกสิบแปดสตางค์");
+}
+
+#[test]
+fn test_arbitrary_length() {
+    // 10^18, 10^24 and 10^36: one more ล้าน for every six digits
+    assert_eq!(
+        words_from("1,000,000,000,000,000,000").unwrap(),
+        "หนึ่งล้านล้านล้านบาทถ้วน"
+    );
+    assert_eq!(
+        words_from("1000000000000000000000000").unwrap(),
+        "หนึ่งล้านล้านล้านล้านบาทถ้วน"
+    );
+    assert_eq!(
+        words_from(&format!("1{}", "0".repeat(36))).unwrap(),
+        "หนึ่งล้านล้านล้านล้านล้านล้านบาทถ้วน"
+    );
+
+    // Beyond u64 and u128
+    assert_eq!(
+        words_from("18446744073709551616").unwrap(),
+        "สิบแปดล้านสี่แสนสี่หมื่นหกพันเจ็ดร้อยสี่สิบสี่ล้านเจ็ดหมื่นสามพันเจ็ดร้อยเก้าล้านห้าแสนห้าหมื่นหนึ่งพันหกร้อยสิบหกบาทถ้วน"
+    );
+    assert_eq!(
+        words_from("-1000000000000000000000000000000000000000001.01").unwrap(),
+        "ลบหนึ่งล้านล้านล้านล้านล้านล้านล้านเอ็ดบาทหนึ่งสตางค์"
+    );
+
+    // A long satang part is rounded exactly
+    assert_eq!(
+        words_from("99999999999999999999.994999999999999999999999").unwrap(),
+        "เก้าสิบเก้าล้านเก้าแสนเก้าหมื่นเก้าพันเก้าร้อยเก้าสิบเก้าล้านเก้าแสนเก้าหมื่นเก้าพันเก้าร้อยเก้าสิบเก้าล้านเก้าแสนเก้าหมื่นเก้าพันเก้าร้อยเก้าสิบเก้าบาทเก้าสิบเก้าสตางค์"
+    );
+    assert_eq!(
+        words_from("99999999999999999999.995").unwrap(),
+        "หนึ่งร้อยล้านล้านล้านบาทถ้วน"
+    );
+}
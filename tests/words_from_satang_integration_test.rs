@@ -0,0 +1,66 @@
+use bahttext::{
+    words, words_from_satang, words_from_satang_i128, words_from_satang_u128, words_from_satang_u64,
+};
+
+#[test]
+fn test_words_from_satang_integration() {
+    assert_eq!(words_from_satang(0), "ศูนย์บาทถ้วน");
+    assert_eq!(words_from_satang(50), "ศูนย์บาทห้าสิบสตางค์");
+    assert_eq!(words_from_satang(2_100), "ยี่สิบเอ็ดบาทถ้วน");
+    assert_eq!(
+        words_from_satang(123_456),
+        "หนึ่งพันสองร้อยสามสิบสี่บาทห้าสิบหกสตางค์"
+    );
+    assert_eq!(
+        words_from_satang(123_456_789),
+        "หนึ่งล้านสองแสนสามหมื่นสี่พันห้าร้อยหกสิบเจ็ดบาทแปดสิบเก้าสตางค์"
+    );
+    assert_eq!(words_from_satang(-100_050), "ลบหนึ่งพันบาทห้าสิบสตางค์");
+}
+
+#[test]
+fn test_words_from_satang_matches_words() {
+    for satang in [
+        0,
+        1,
+        10,
+        11,
+        99,
+        100,
+        101,
+        2_525,
+        100_000_001,
+        999_999_999_999,
+    ] {
+        let expect = words(satang as f64 / 100.0);
+
+        assert_eq!(words_from_satang(satang), expect, "Fail case: {}", satang);
+        assert_eq!(
+            words_from_satang_u64(satang as u64),
+            expect,
+            "Fail case: {}",
+            satang
+        );
+        assert_eq!(
+            words_from_satang_i128(satang.into()),
+            expect,
+            "Fail case: {}",
+            satang
+        );
+        assert_eq!(
+            words_from_satang_u128(satang as u128),
+            expect,
+            "Fail case: {}",
+            satang
+        );
+    }
+}
+
+#[test]
+fn test_words_from_satang_beyond_f64_precision() {
+    // 2^53 + 1 satang cannot be represented as an f64
+    assert_eq!(
+        words_from_satang_u64(9_007_199_254_740_993),
+        "เก้าสิบล้านเจ็ดหมื่นหนึ่งพันเก้าร้อยเก้าสิบสองล้านห้าแสนสี่หมื่นเจ็ดพันสี่ร้อยเก้าบาทเก้าสิบสามสตางค์"
+    );
+}